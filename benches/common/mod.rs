@@ -0,0 +1,198 @@
+//! The synthetic dense block shared by the `map_events` benchmarks: every transaction carries
+//! ERC-20 `Transfer` noise from an untracked token, which shares its signature with the NFT
+//! `Transfer`, and every tenth one a mix of NFT and attester events from the tracked deployments.
+
+use ethabi::{long_signature, ParamType, Token};
+use substreams::Hex;
+use substreams_ethereum::pb::eth::v2 as eth;
+
+pub const NFT: [u8; 20] = [0x11; 20];
+pub const ATTESTER: [u8; 20] = [0x22; 20];
+pub const NOISE: [u8; 20] = [0x33; 20];
+
+pub const TRANSACTIONS: usize = 500;
+// Every tenth transaction touches the tracked contracts; all of them emit noise, as on a
+// busy chain where the tracked deployments account for a small share of the logs.
+pub const TRACKED_EVERY: usize = 10;
+pub const NOISE_LOGS: usize = 12;
+
+fn topic(name: &str, params: &[ParamType]) -> Vec<u8> {
+    long_signature(name, params).as_bytes().to_vec()
+}
+
+fn word(token: Token) -> Vec<u8> {
+    ethabi::encode(&[token])
+}
+
+fn address(byte: u8) -> Token {
+    Token::Address([byte; 20].into())
+}
+
+fn log(address: &[u8; 20], topics: Vec<Vec<u8>>, data: Vec<u8>) -> eth::Log {
+    eth::Log {
+        address: address.to_vec(),
+        topics,
+        data,
+        ..Default::default()
+    }
+}
+
+fn transaction_logs(i: usize) -> Vec<eth::Log> {
+    let token_id = word(Token::Uint(i.into()));
+    let request_id = word(Token::FixedBytes(vec![i as u8; 32]));
+    let transfer = topic(
+        "Transfer",
+        &[ParamType::Address, ParamType::Address, ParamType::Uint(256)],
+    );
+
+    let mut logs: Vec<eth::Log> = (0..NOISE_LOGS)
+        .map(|n| {
+            let amount = word(Token::Uint(n.into()));
+            log(
+                &NOISE,
+                vec![transfer.clone(), word(address(1)), word(address(2))],
+                amount,
+            )
+        })
+        .collect();
+    if !i.is_multiple_of(TRACKED_EVERY) {
+        return logs;
+    }
+
+    logs.extend([
+        log(
+            &NFT,
+            vec![
+                transfer.clone(),
+                word(address(0)),
+                word(address(1)),
+                token_id.clone(),
+            ],
+            vec![],
+        ),
+        log(
+            &NFT,
+            vec![
+                topic(
+                    "Approval",
+                    &[ParamType::Address, ParamType::Address, ParamType::Uint(256)],
+                ),
+                word(address(1)),
+                word(address(2)),
+                token_id.clone(),
+            ],
+            vec![],
+        ),
+        log(
+            &NFT,
+            vec![topic("MetadataUpdate", &[ParamType::Uint(256)])],
+            ethabi::encode(&[Token::Uint(i.into())]),
+        ),
+        log(
+            &NFT,
+            vec![
+                topic(
+                    "RoleGranted",
+                    &[
+                        ParamType::FixedBytes(32),
+                        ParamType::Address,
+                        ParamType::Address,
+                    ],
+                ),
+                word(Token::FixedBytes(vec![0; 32])),
+                word(address(1)),
+                word(address(2)),
+            ],
+            vec![],
+        ),
+        log(
+            &ATTESTER,
+            vec![
+                topic("RequestSent", &[ParamType::FixedBytes(32)]),
+                request_id.clone(),
+            ],
+            vec![],
+        ),
+        log(
+            &ATTESTER,
+            vec![
+                topic(
+                    "Response",
+                    &[
+                        ParamType::FixedBytes(32),
+                        ParamType::Bytes,
+                        ParamType::Bytes,
+                    ],
+                ),
+                request_id.clone(),
+            ],
+            ethabi::encode(&[Token::Bytes(b"verified".to_vec()), Token::Bytes(vec![])]),
+        ),
+        log(
+            &ATTESTER,
+            vec![
+                topic("RequestFulfilled", &[ParamType::FixedBytes(32)]),
+                request_id.clone(),
+            ],
+            vec![],
+        ),
+        log(
+            &ATTESTER,
+            vec![
+                topic(
+                    "Attested",
+                    &[
+                        ParamType::FixedBytes(32),
+                        ParamType::Address,
+                        ParamType::String,
+                        ParamType::String,
+                    ],
+                ),
+                request_id,
+                word(address(1)),
+            ],
+            ethabi::encode(&[
+                Token::String("attester".into()),
+                Token::String("ipfs://photo".into()),
+            ]),
+        ),
+    ]);
+    logs
+}
+
+pub fn dense_block() -> eth::Block {
+    let mut block_index = 0;
+    let transaction_traces = (0..TRANSACTIONS)
+        .map(|i| {
+            let mut logs = transaction_logs(i);
+            for log in logs.iter_mut() {
+                log.block_index = block_index;
+                block_index += 1;
+            }
+            eth::TransactionTrace {
+                hash: vec![i as u8; 32],
+                status: eth::TransactionTraceStatus::Succeeded as i32,
+                receipt: Some(eth::TransactionReceipt {
+                    logs,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }
+        })
+        .collect();
+
+    eth::Block {
+        number: 17968303,
+        header: Some(eth::BlockHeader {
+            timestamp: Some(prost_types::Timestamp::default()),
+            ..Default::default()
+        }),
+        transaction_traces,
+        ..Default::default()
+    }
+}
+
+/// `map_events` params tracking the NFT and attester deployments of the block.
+pub fn params() -> String {
+    format!("evt_addr:0x{} || evt_addr:0x{}", Hex(&NFT), Hex(&ATTESTER))
+}
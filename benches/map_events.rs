@@ -0,0 +1,74 @@
+//! Compares the single-pass `map_events` dispatch against the previous layout, which
+//! rescanned every receipt of the block once per event type, over the dense block of
+//! `common`.
+//!
+//! Both layouts run natively and criterion reports wall-clock time; `map_events_wasm` counts
+//! the WASM instructions of the same two layouts.
+
+mod common;
+
+use common::{TRACKED_EVERY, TRANSACTIONS};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use miracam::abi::{attester_contract, nft_contract};
+use miracam::params::TrackedContracts;
+use substreams::Hex;
+use substreams_ethereum::pb::eth::v2 as eth;
+use substreams_ethereum::Event;
+
+/// One full receipt scan for a single event type, as each of the fourteen per-type
+/// collectors used to do. Events are paired with their hex transaction hash so the
+/// baseline pays the same per-event formatting as the real mapping.
+fn scan<E: Event>(blk: &eth::Block, contracts: &TrackedContracts) -> Vec<(String, E)> {
+    blk.receipts()
+        .flat_map(|view| {
+            view.receipt
+                .logs
+                .iter()
+                .filter(|log| contracts.contains(&log.address))
+                .filter_map(move |log| {
+                    E::match_and_decode(log)
+                        .map(|event| (Hex(&view.transaction.hash).to_string(), event))
+                })
+        })
+        .collect()
+}
+
+fn per_type_scans(blk: &eth::Block, contracts: &TrackedContracts) -> usize {
+    scan::<nft_contract::events::Approval>(blk, contracts).len()
+        + scan::<nft_contract::events::ApprovalForAll>(blk, contracts).len()
+        + scan::<nft_contract::events::BatchMetadataUpdate>(blk, contracts).len()
+        + scan::<nft_contract::events::MetadataUpdate>(blk, contracts).len()
+        + scan::<nft_contract::events::RoleAdminChanged>(blk, contracts).len()
+        + scan::<nft_contract::events::RoleGranted>(blk, contracts).len()
+        + scan::<nft_contract::events::RoleRevoked>(blk, contracts).len()
+        + scan::<nft_contract::events::Transfer>(blk, contracts).len()
+        + scan::<attester_contract::events::Attested>(blk, contracts).len()
+        + scan::<attester_contract::events::OwnershipTransferRequested>(blk, contracts).len()
+        + scan::<attester_contract::events::OwnershipTransferred>(blk, contracts).len()
+        + scan::<attester_contract::events::RequestFulfilled>(blk, contracts).len()
+        + scan::<attester_contract::events::RequestSent>(blk, contracts).len()
+        + scan::<attester_contract::events::Response>(blk, contracts).len()
+}
+
+fn bench_map_events(c: &mut Criterion) {
+    let blk = common::dense_block();
+    let contracts = TrackedContracts::parse(&common::params(), "evt_addr").unwrap();
+
+    let events = miracam::map_tracked_events(&blk, &contracts);
+    let tracked = TRANSACTIONS / TRACKED_EVERY;
+    assert_eq!(events.nft_transfers.len(), tracked);
+    assert_eq!(events.attester_attesteds.len(), tracked);
+    assert_eq!(per_type_scans(&blk, &contracts), 8 * tracked);
+
+    let mut group = c.benchmark_group("map_events");
+    group.bench_function("per_type_scans", |b| {
+        b.iter(|| per_type_scans(black_box(&blk), &contracts))
+    });
+    group.bench_function("single_pass", |b| {
+        b.iter(|| miracam::map_tracked_events(black_box(&blk), &contracts))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_map_events);
+criterion_main!(benches);
@@ -0,0 +1,167 @@
+//! Counts the WASM instructions `map_events` executes over the dense block of `common`,
+//! against the per-type receipt scans it replaced, by running the compiled `miracam.wasm`
+//! under wasmtime with fuel metering. Wasmtime charges one unit of fuel per WASM operator,
+//! except for `nop`, `drop` and the structured control operators (`block`, `loop`, `end`,
+//! ...), which are free.
+//!
+//! The module is built with the `bench-baseline` feature, which adds the
+//! `map_events_per_type_scans` export, into its own target directory so the packaged
+//! `miracam.wasm` never carries it.
+
+mod common;
+
+use common::{TRACKED_EVERY, TRANSACTIONS};
+use miracam::params::TrackedContracts;
+use miracam::pb::contract::v1 as contract;
+use prost::Message;
+use std::path::PathBuf;
+use std::process::Command;
+use wasmtime::{Caller, Config, Engine, Instance, Linker, Memory, Module, Store};
+
+const TARGET_DIR: &str = "target/bench-baseline";
+
+#[derive(Default)]
+struct Host {
+    output: Vec<u8>,
+    panic: Option<String>,
+}
+
+fn memory(caller: &mut Caller<'_, Host>) -> Memory {
+    caller
+        .get_export("memory")
+        .and_then(|export| export.into_memory())
+        .expect("miracam.wasm exports its memory")
+}
+
+fn read(caller: &mut Caller<'_, Host>, ptr: i32, len: i32) -> Vec<u8> {
+    let mut bytes = vec![0; len as usize];
+    memory(caller)
+        .read(&caller, ptr as usize, &mut bytes)
+        .expect("pointer inside the module memory");
+    bytes
+}
+
+fn build_module() -> PathBuf {
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+    let status = Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".into()))
+        .current_dir(manifest_dir)
+        .args([
+            "build",
+            "--release",
+            "--target",
+            "wasm32-unknown-unknown",
+            "--features",
+            "bench-baseline",
+            "--target-dir",
+            TARGET_DIR,
+        ])
+        .status()
+        .expect("cargo runs");
+    assert!(status.success(), "building miracam.wasm failed");
+    PathBuf::from(manifest_dir).join(format!(
+        "{}/wasm32-unknown-unknown/release/miracam.wasm",
+        TARGET_DIR
+    ))
+}
+
+/// Runs the `handler` export over `block` and returns the fuel it consumed with its output.
+fn run(
+    engine: &Engine,
+    module: &Module,
+    handler: &str,
+    params: &str,
+    block: &[u8],
+) -> (u64, contract::Events) {
+    let mut linker = Linker::new(engine);
+    linker
+        .func_wrap(
+            "env",
+            "output",
+            |mut caller: Caller<'_, Host>, ptr: i32, len: i32| {
+                caller.data_mut().output = read(&mut caller, ptr, len);
+            },
+        )
+        .unwrap()
+        .func_wrap("env", "skip_empty_output", || {})
+        .unwrap()
+        .func_wrap(
+            "env",
+            "register_panic",
+            |mut caller: Caller<'_, Host>, ptr: i32, len: i32, _: i32, _: i32, _: i32, _: i32| {
+                let message = read(&mut caller, ptr, len);
+                caller.data_mut().panic = Some(String::from_utf8_lossy(&message).into_owned());
+            },
+        )
+        .unwrap()
+        .func_wrap("logger", "println", |_: i32, _: i32| {})
+        .unwrap();
+    // `map_events` reads no store, so the `state` imports are never called.
+    linker.define_unknown_imports_as_traps(module).unwrap();
+
+    let mut store = Store::new(engine, Host::default());
+    store.set_fuel(u64::MAX).unwrap();
+    let instance: Instance = linker.instantiate(&mut store, module).unwrap();
+    let memory = instance.get_memory(&mut store, "memory").unwrap();
+    let alloc = instance
+        .get_typed_func::<i32, i32>(&mut store, "alloc")
+        .unwrap();
+    let write = |store: &mut Store<Host>, bytes: &[u8]| {
+        let ptr = alloc.call(&mut *store, bytes.len() as i32).unwrap();
+        memory.write(&mut *store, ptr as usize, bytes).unwrap();
+        (ptr, bytes.len() as i32)
+    };
+    let (params_ptr, params_len) = write(&mut store, params.as_bytes());
+    let (block_ptr, block_len) = write(&mut store, block);
+
+    let handler = instance
+        .get_typed_func::<(i32, i32, i32, i32), ()>(&mut store, handler)
+        .unwrap_or_else(|err| panic!("{} export: {}", handler, err));
+    let before = store.get_fuel().unwrap();
+    let result = handler.call(&mut store, (params_ptr, params_len, block_ptr, block_len));
+    let fuel = before - store.get_fuel().unwrap();
+    if let Err(err) = result {
+        panic!("{:?}: {}", store.data().panic, err);
+    }
+
+    let events = contract::Events::decode(store.data().output.as_slice()).unwrap();
+    (fuel, events)
+}
+
+fn main() {
+    let blk = common::dense_block();
+    let params = common::params();
+    let contracts = TrackedContracts::parse(&params, "evt_addr").unwrap();
+    let expected = miracam::map_tracked_events(&blk, &contracts);
+    let tracked = TRANSACTIONS / TRACKED_EVERY;
+    assert_eq!(expected.nft_transfers.len(), tracked);
+    assert_eq!(expected.attester_attesteds.len(), tracked);
+
+    let engine = Engine::new(Config::new().consume_fuel(true)).unwrap();
+    let module = Module::from_file(&engine, build_module()).unwrap();
+    let block = blk.encode_to_vec();
+
+    let (per_type_scans, baseline) = run(
+        &engine,
+        &module,
+        "map_events_per_type_scans",
+        &params,
+        &block,
+    );
+    let (single_pass, events) = run(&engine, &module, "map_events", &params, &block);
+    assert_eq!(baseline, expected);
+    assert_eq!(events, expected);
+
+    println!(
+        "map_events over {} transactions, {} logs",
+        TRANSACTIONS,
+        blk.receipts()
+            .map(|view| view.receipt.logs.len())
+            .sum::<usize>()
+    );
+    println!("  per_type_scans: {:>12} WASM instructions", per_type_scans);
+    println!("  single_pass:    {:>12} WASM instructions", single_pass);
+    println!(
+        "  reduction:      {:>11.1}%",
+        100.0 * (1.0 - single_pass as f64 / per_type_scans as f64)
+    );
+}
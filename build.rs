@@ -1,42 +1,905 @@
-use anyhow::{Ok, Result};
+use anyhow::{anyhow, Context, Result};
+use ethabi::{Contract, ParamType, StateMutability};
+use heck::{ToSnakeCase, ToUpperCamelCase};
 use regex::Regex;
-use substreams_ethereum::Abigen;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs;
+use substreams_ethereum::Abigen;
+
+const ABI_DIR: &str = "abi";
+const ABI_SUFFIX: &str = "_contract.abi.json";
+const EVENTS_PROTO: &str = "proto/events.proto";
+const MAPPING_RS: &str = "src/mapping.rs";
+
+/// (field, proto type) pairs.
+type Fields = &'static [(&'static str, &'static str)];
+
+// Fields filled in by `lib.rs` rather than decoded from the log, appended to the generated
+// message after the event's own inputs: (contract, event, fields).
+const DERIVED_FIELDS: &[(&str, &str, Fields)] = &[(
+    "attester",
+    "Response",
+    &[
+        ("response_text", "string"),
+        ("error_text", "string"),
+        ("is_error", "bool"),
+    ],
+)];
+
+/// One event or function of a contract ABI, named the way `Abigen` names it.
+struct Item {
+    /// `Abigen` struct name, e.g. `SafeTransferFrom1`.
+    rust_name: String,
+    /// Snake-cased name used for the `Events`/`Calls` list, e.g. `safe_transfer_from_1`.
+    list_name: String,
+    /// Event signature hash, matched against `topics[0]`; `None` for calls.
+    topic: Option<[u8; 32]>,
+    fields: Vec<Field>,
+    derived: Fields,
+}
+
+struct Field {
+    name: String,
+    proto_type: String,
+    repeated: bool,
+    conversion: Conversion,
+    /// Also emitted as a `BigInt` message in `<name>_bigint`, for integers wider than 64 bits.
+    bigint: bool,
+}
+
+enum Conversion {
+    Move,
+    FixedBytes,
+    ToString,
+    ToU64,
+    ToI32,
+    Each(Box<Conversion>),
+}
+
+struct ContractAbi {
+    name: String,
+    events: Vec<Item>,
+    calls: Vec<Item>,
+    /// Selector and ABI name of every function, views included.
+    selectors: Vec<([u8; 4], String)>,
+    errors: Vec<ErrorAbi>,
+}
+
+struct ErrorAbi {
+    selector: [u8; 4],
+    name: String,
+    inputs: Vec<(String, ParamType)>,
+}
 
 fn main() -> Result<(), anyhow::Error> {
-    let file_names = [
-        "abi/nft_contract.abi.json",
-        "abi/attester_contract.abi.json",
-    ];
-    let file_output_names = [
-        "src/abi/nft_contract.rs",
-        "src/abi/attester_contract.rs",
-    ];
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed={}", ABI_DIR);
+
+    let mut names = fs::read_dir(ABI_DIR)?
+        .map(|entry| Ok(entry?.file_name().to_string_lossy().into_owned()))
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .filter_map(|file_name| file_name.strip_suffix(ABI_SUFFIX).map(str::to_string))
+        .collect::<Vec<_>>();
+    names.sort();
 
-    let mut i = 0;
-    for f in file_names {
-        let contents = fs::read_to_string(f)
+    // sanitize fields and attributes starting with an underscore
+    let regex = Regex::new(r#"("\w+"\s?:\s?")_(\w+")"#).unwrap();
+    // sanitize fields and attributes with multiple consecutive underscores
+    let re = Regex::new(r"_+").unwrap();
+
+    let mut contracts = Vec::new();
+    for name in &names {
+        let contents = fs::read_to_string(format!("{}/{}{}", ABI_DIR, name, ABI_SUFFIX))
             .expect("Should have been able to read the file");
 
-        // sanitize fields and attributes starting with an underscore
-        let regex = Regex::new(r#"("\w+"\s?:\s?")_(\w+")"#).unwrap();
         let sanitized_abi_file = regex.replace_all(contents.as_str(), "${1}u_${2}");
 
-        // sanitize fields and attributes with multiple consecutive underscores
-        let re = Regex::new(r"_+").unwrap();
-
-        let re_sanitized_abi_file = re.replace_all(&sanitized_abi_file, |caps: &regex::Captures| {
+        let re_sanitized_abi_file =
+            re.replace_all(&sanitized_abi_file, |caps: &regex::Captures| {
                 let count = caps[0].len();
                 let replacement = format!("{}_", "_u".repeat(count - 1));
                 replacement
-        });
+            });
 
         Abigen::from_bytes("Contract", re_sanitized_abi_file.as_bytes())?
             .generate()?
-            .write_to_file(file_output_names[i])?;
+            .write_to_file(format!("src/abi/{}_contract.rs", name))?;
+
+        let abi = Contract::load(re_sanitized_abi_file.as_bytes())?;
+        contracts.push(
+            contract_abi(name, &abi)
+                .with_context(|| format!("unsupported ABI {}{}", name, ABI_SUFFIX))?,
+        );
+    }
+
+    let abi_mod = names
+        .iter()
+        .map(|name| format!("pub mod {}_contract;\n", name))
+        .collect::<String>();
+    write_if_changed("src/abi/mod.rs", &abi_mod)?;
+
+    let existing_proto = fs::read_to_string(EVENTS_PROTO).unwrap_or_default();
+    write_if_changed(EVENTS_PROTO, &events_proto(&contracts, &existing_proto))?;
+    write_if_changed(MAPPING_RS, &mapping_rs(&contracts))?;
+
+    Ok(())
+}
+
+fn contract_abi(name: &str, abi: &Contract) -> Result<ContractAbi> {
+    let mut events = Vec::new();
+    for overloads in abi.events.values() {
+        for (index, event) in overloads.iter().enumerate() {
+            let (rust_name, list_name) = overloaded_names(&event.name, index, overloads.len());
+            let fields = event
+                .inputs
+                .iter()
+                .enumerate()
+                .map(|(i, input)| {
+                    let fallback = if input.indexed { "topic" } else { "param" };
+                    field(&input.name, i, fallback, &input.kind)
+                })
+                .collect::<Result<_>>()
+                .with_context(|| format!("event {}", event.name))?;
+            let derived = DERIVED_FIELDS
+                .iter()
+                .find(|(contract, event, _)| *contract == name && *event == rust_name)
+                .map(|(_, _, fields)| *fields)
+                .unwrap_or_default();
+            events.push(Item {
+                rust_name,
+                list_name,
+                topic: Some(event.signature().0),
+                fields,
+                derived,
+            });
+        }
+    }
+    events.sort_by(|left, right| left.rust_name.cmp(&right.rust_name));
+
+    // `Abigen` groups overloads by their camel-cased name, not the raw ABI name.
+    let mut functions = BTreeMap::<String, Vec<_>>::new();
+    for function in abi.functions() {
+        if !matches!(
+            function.state_mutability,
+            StateMutability::View | StateMutability::Pure
+        ) {
+            functions
+                .entry(function.name.to_upper_camel_case())
+                .or_default()
+                .push(function);
+        }
+    }
+    let mut calls = Vec::new();
+    for overloads in functions.values() {
+        for (index, function) in overloads.iter().enumerate() {
+            let (rust_name, list_name) = overloaded_names(&function.name, index, overloads.len());
+            let fields = function
+                .inputs
+                .iter()
+                .enumerate()
+                .map(|(i, input)| field(&input.name, i, "param", &input.kind))
+                .collect::<Result<_>>()
+                .with_context(|| format!("function {}", function.name))?;
+            calls.push(Item {
+                rust_name,
+                list_name,
+                topic: None,
+                fields,
+                derived: &[],
+            });
+        }
+    }
+    calls.sort_by(|left, right| left.rust_name.cmp(&right.rust_name));
+
+    let selectors = abi
+        .functions()
+        .map(|function| (function.short_signature(), function.name.clone()))
+        .collect();
+    let errors = abi
+        .errors()
+        .map(|error| ErrorAbi {
+            selector: error.signature().as_bytes()[..4].try_into().unwrap(),
+            name: error.name.clone(),
+            inputs: error
+                .inputs
+                .iter()
+                .map(|input| (input.name.clone(), input.kind.clone()))
+                .collect(),
+        })
+        .collect();
+
+    Ok(ContractAbi {
+        name: name.to_string(),
+        events,
+        calls,
+        selectors,
+        errors,
+    })
+}
+
+fn overloaded_names(name: &str, index: usize, overloads: usize) -> (String, String) {
+    let camel = name.to_upper_camel_case();
+    if overloads <= 1 {
+        (camel, name.to_snake_case())
+    } else {
+        (
+            format!("{}{}", camel, index + 1),
+            format!("{}_{}", name.to_snake_case(), index + 1),
+        )
+    }
+}
+
+fn field(name: &str, index: usize, fallback: &str, kind: &ParamType) -> Result<Field> {
+    let name = match name {
+        "" => format!("{}{}", fallback, index),
+        "self" => "_self".to_string(),
+        name => name.to_snake_case(),
+    };
+    let (proto_type, repeated, conversion) = match kind {
+        ParamType::Array(inner) => {
+            let (proto_type, conversion) = scalar(inner)?;
+            let conversion = match conversion {
+                Conversion::Move => Conversion::Move,
+                conversion => Conversion::Each(Box::new(conversion)),
+            };
+            (proto_type, true, conversion)
+        }
+        kind => {
+            let (proto_type, conversion) = scalar(kind)?;
+            (proto_type, false, conversion)
+        }
+    };
+
+    let bigint = matches!(kind, ParamType::Uint(size) if *size > 64);
+
+    Ok(Field {
+        name,
+        proto_type: proto_type.to_string(),
+        repeated,
+        conversion,
+        bigint,
+    })
+}
+
+fn scalar(kind: &ParamType) -> Result<(&'static str, Conversion)> {
+    Ok(match kind {
+        ParamType::Address | ParamType::Bytes => ("bytes", Conversion::Move),
+        ParamType::FixedBytes(_) => ("bytes", Conversion::FixedBytes),
+        ParamType::Uint(size) if *size <= 64 => ("uint64", Conversion::ToU64),
+        ParamType::Int(size) if *size <= 32 => ("int32", Conversion::ToI32),
+        ParamType::Uint(_) | ParamType::Int(_) => ("string", Conversion::ToString),
+        ParamType::Bool => ("bool", Conversion::Move),
+        ParamType::String => ("string", Conversion::Move),
+        kind => return Err(anyhow!("no protobuf mapping for ABI type {}", kind)),
+    })
+}
+
+// Where the item `checked_fields` decodes sits in the block, for the log line of a skipped one:
+// the format string of the position and its arguments.
+struct Position<'a> {
+    item: String,
+    format: &'a str,
+    args: &'a str,
+}
+
+// Integers of up to 64 bits decode as a `BigInt`, which data wider than the ABI type overflows.
+// Those values are not valid encodings, so the item is logged and skipped (`skip`) rather than
+// panicking in `BigInt::to_u64`; the checked values are bound to the field names before the
+// message is built.
+fn checked_fields(
+    fields: &[Field],
+    decoded: &str,
+    position: &Position,
+    skip: &str,
+    indent: &str,
+) -> String {
+    fields
+        .iter()
+        .filter_map(|field| {
+            let value = format!("{}.{}", decoded, field.name);
+            let checked = match &field.conversion {
+                Conversion::ToU64 => format!("crate::numeric::checked_u64(&{})", value),
+                Conversion::Each(inner) if matches!(**inner, Conversion::ToU64) => {
+                    format!(
+                        "{}.iter().map(crate::numeric::checked_u64).collect::<Option<Vec<_>>>()",
+                        value
+                    )
+                }
+                _ => return None,
+            };
+            Some(format!(
+                "{}let Some({}) = {} else {{ substreams::log::info!(\"skipping {} at {}: {} overflows 64 bits\", {}); {} }};\n",
+                indent,
+                field.name,
+                checked,
+                position.item,
+                position.format,
+                field.name,
+                position.args,
+                skip
+            ))
+        })
+        .collect()
+}
+
+fn field_value(field: &Field, decoded: &str) -> String {
+    match &field.conversion {
+        Conversion::ToU64 => field.name.clone(),
+        Conversion::Each(inner) if matches!(**inner, Conversion::ToU64) => field.name.clone(),
+        conversion => format!(
+            "{}: {}",
+            field.name,
+            convert(conversion, &format!("{}.{}", decoded, field.name))
+        ),
+    }
+}
+
+fn convert(conversion: &Conversion, value: &str) -> String {
+    match conversion {
+        Conversion::Move => value.to_string(),
+        Conversion::FixedBytes => format!("Vec::from({})", value),
+        Conversion::ToString => format!("{}.to_string()", value),
+        Conversion::ToU64 => unreachable!("{} is bound by checked_fields", value),
+        Conversion::ToI32 => format!("{}.to_i32()", value),
+        Conversion::Each(inner) => format!(
+            "{}.into_iter().map(|value| {}).collect()",
+            value,
+            convert(inner, "value")
+        ),
+    }
+}
+
+fn message_name(contract: &str, item: &Item, suffix: &str) -> String {
+    format!(
+        "{}_{}{}",
+        contract.to_upper_camel_case(),
+        item.rust_name,
+        suffix
+    )
+}
+
+fn rust_message_name(contract: &str, item: &Item, suffix: &str) -> String {
+    format!(
+        "{}{}{}",
+        contract.to_upper_camel_case(),
+        item.rust_name,
+        suffix
+    )
+}
+
+fn event_list(contract: &str, item: &Item) -> String {
+    format!("{}_{}s", contract, item.list_name)
+}
+
+fn call_list(contract: &str, item: &Item) -> String {
+    format!("{}_call_{}s", contract, item.list_name)
+}
+
+/// Tags of the fields of `message` in a previously generated proto.
+fn existing_tags(proto: &str, message: &str) -> BTreeMap<String, u32> {
+    let field = Regex::new(r"^\s*(?:repeated\s+)?[\w.]+\s+(\w+)\s*=\s*(\d+);").unwrap();
+    message_lines(proto, message)
+        .filter_map(|line| field.captures(line))
+        .map(|caps| (caps[1].to_string(), caps[2].parse().unwrap()))
+        .collect()
+}
+
+/// Tags `message` reserved in a previously generated proto.
+fn reserved_tags(proto: &str, message: &str) -> BTreeSet<u32> {
+    let reserved = Regex::new(r"^\s*reserved\s+([\d,\s]+);").unwrap();
+    message_lines(proto, message)
+        .filter_map(|line| reserved.captures(line))
+        .flat_map(|caps| {
+            caps[1]
+                .split(',')
+                .map(|tag| tag.trim().parse().unwrap())
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+fn message_lines<'a>(proto: &'a str, message: &str) -> impl Iterator<Item = &'a str> {
+    let start = format!("message {} {{", message);
+    proto
+        .lines()
+        .skip_while(move |line| line.trim() != start)
+        .skip(1)
+        .take_while(|line| line.trim() != "}")
+}
+
+/// Numbers the `fields` (type, name) of `message`, keeping the tag a field had in the previous
+/// generation so that changing an ABI never renumbers fields consumers decode. New fields are
+/// numbered after every tag used so far, in order, and the tags of removed fields are reserved
+/// so they are never handed to another field.
+fn numbered_message(message: &str, fields: Vec<(String, String)>, existing_proto: &str) -> String {
+    let existing = existing_tags(existing_proto, message);
+    let mut reserved = reserved_tags(existing_proto, message);
+    reserved.extend(
+        existing
+            .iter()
+            .filter(|(name, _)| !fields.iter().any(|(_, field)| field == *name))
+            .map(|(_, tag)| *tag),
+    );
+
+    let mut next = existing
+        .values()
+        .chain(&reserved)
+        .max()
+        .copied()
+        .unwrap_or_default()
+        + 1;
+    let mut tagged = fields
+        .into_iter()
+        .map(|(kind, name)| {
+            let tag = existing.get(&name).copied().unwrap_or_else(|| {
+                next += 1;
+                next - 1
+            });
+            (tag, kind, name)
+        })
+        .collect::<Vec<_>>();
+    tagged.sort();
+
+    let mut out = format!("message {} {{\n", message);
+    for (tag, kind, name) in tagged {
+        out.push_str(&format!("    {} {} = {};\n", kind, name, tag));
+    }
+    if !reserved.is_empty() {
+        let tags = reserved.iter().map(u32::to_string).collect::<Vec<_>>();
+        out.push_str(&format!("    reserved {};\n", tags.join(", ")));
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// The `Events` or `Calls` message: one list (field name, message name) per ABI item.
+fn list_message(message: &str, lists: Vec<(String, String)>, existing_proto: &str) -> String {
+    let fields = lists
+        .into_iter()
+        .map(|(field, message)| (format!("repeated {}", message), field))
+        .collect();
+    numbered_message(message, fields, existing_proto)
+}
+
+// On the first generation, fields are numbered in the order they are listed here, so later
+// additions (`BigInt` companions, `evt_context`) come last.
+fn item_message(
+    name: &str,
+    header: &[(&str, &str)],
+    item: &Item,
+    is_event: bool,
+    existing_proto: &str,
+) -> String {
+    let mut fields = header
+        .iter()
+        .map(|(name, kind)| (kind.to_string(), name.to_string()))
+        .collect::<Vec<_>>();
+    for field in &item.fields {
+        let kind = if field.repeated {
+            format!("repeated {}", field.proto_type)
+        } else {
+            field.proto_type.clone()
+        };
+        fields.push((kind, field.name.clone()));
+    }
+    fields.extend(
+        item.derived
+            .iter()
+            .map(|(name, kind)| (kind.to_string(), name.to_string())),
+    );
+    fields.push(("bytes".to_string(), "contract_address".to_string()));
+    for field in item.fields.iter().filter(|field| field.bigint) {
+        fields.push(("BigInt".to_string(), format!("{}_bigint", field.name)));
+    }
+    if is_event {
+        fields.push(("EventContext".to_string(), "evt_context".to_string()));
+    }
+    numbered_message(name, fields, existing_proto)
+}
+
+fn events_proto(contracts: &[ContractAbi], existing_proto: &str) -> String {
+    const EVENT_HEADER: &[(&str, &str)] = &[
+        ("evt_tx_hash", "string"),
+        ("evt_index", "uint32"),
+        ("evt_block_time", "google.protobuf.Timestamp"),
+        ("evt_block_number", "uint64"),
+    ];
+    const CALL_HEADER: &[(&str, &str)] = &[
+        ("call_tx_hash", "string"),
+        ("call_block_time", "google.protobuf.Timestamp"),
+        ("call_block_number", "uint64"),
+        ("call_ordinal", "uint64"),
+        ("call_success", "bool"),
+        ("call_caller", "bytes"),
+        ("call_value", "string"),
+        ("call_gas_used", "uint64"),
+    ];
+
+    let mut out = String::from(
+        "// @generated by build.rs from abi/*.abi.json, do not edit.\n\
+         syntax = \"proto3\";\n\n\
+         import \"google/protobuf/timestamp.proto\";\n\
+         import \"contract.proto\";\n\n\
+         package contract.v1;\n\n",
+    );
+
+    let event_lists = contracts
+        .iter()
+        .flat_map(|c| {
+            c.events
+                .iter()
+                .map(|e| (event_list(&c.name, e), message_name(&c.name, e, "")))
+        })
+        .collect();
+    out.push_str(&list_message("Events", event_lists, existing_proto));
+    for contract in contracts {
+        for event in &contract.events {
+            out.push_str(&item_message(
+                &message_name(&contract.name, event, ""),
+                EVENT_HEADER,
+                event,
+                true,
+                existing_proto,
+            ));
+        }
+    }
 
-        i = i+1;
+    out.push('\n');
+    let call_lists = contracts
+        .iter()
+        .flat_map(|c| {
+            c.calls
+                .iter()
+                .map(|f| (call_list(&c.name, f), message_name(&c.name, f, "Call")))
+        })
+        .collect();
+    out.push_str(&list_message("Calls", call_lists, existing_proto));
+    for contract in contracts {
+        for call in &contract.calls {
+            out.push_str(&item_message(
+                &message_name(&contract.name, call, "Call"),
+                CALL_HEADER,
+                call,
+                false,
+                existing_proto,
+            ));
+        }
     }
+    out
+}
+
+fn mapping_rs(contracts: &[ContractAbi]) -> String {
+    // Contracts without state-changing functions, like the router, have no calls to decode.
+    let with_calls = contracts
+        .iter()
+        .filter(|contract| !contract.calls.is_empty())
+        .collect::<Vec<_>>();
+
+    let mut out = String::from(
+        "// @generated by build.rs from abi/*.abi.json, do not edit.\n\
+         use crate::abi;\n\
+         use crate::params::TrackedContracts;\n\
+         use crate::pb::contract::v1 as contract;\n\
+         use hex_literal::hex;\n\
+         use substreams::Hex;\n\
+         use substreams_ethereum::pb::eth::v2 as eth;\n\
+         use substreams_ethereum::Event;\n",
+    );
+    if !with_calls.is_empty() {
+        out.push_str("use substreams_ethereum::Function;\n");
+    }
+    out.push('\n');
 
+    // A log is decoded at most once: its `topics[0]` selects the single event it can be. Signatures
+    // shared by several ABIs, like the ownership events, go to the first contract by name.
+    let mut topics = BTreeSet::new();
+    let arms = contracts
+        .iter()
+        .flat_map(|contract| contract.events.iter().map(move |event| (contract, event)))
+        .filter(|(_, event)| topics.insert(event.topic))
+        .map(|(contract, event)| event_arm(contract, event))
+        .collect::<String>();
+    out.push_str(&format!(
+        "/// Decodes `log` with the ABI event matching its signature and appends it to `events`.\n\
+         /// Returns `false` when the log is not one of the ABI events.\n\
+         pub fn map_event(blk: &eth::Block, trx: &eth::TransactionTrace, log: &eth::Log, events: &mut contract::Events) -> bool {{\n\
+         \x20   match topic0(log) {{\n{}\
+         \x20       _ => false,\n    }}\n}}\n\n\
+         fn topic0(log: &eth::Log) -> Option<[u8; 32]> {{\n\
+         \x20   log.topics.first()?.as_slice().try_into().ok()\n}}\n",
+        arms
+    ));
+
+    out.push_str(
+        "\n/// Decodes the calls made to `contracts` with the ABI functions and appends them to `calls`.\n\
+         pub fn map_calls(blk: &eth::Block, contracts: &TrackedContracts, calls: &mut contract::Calls) {\n",
+    );
+    if with_calls.is_empty() {
+        out.push_str("    let _ = (blk, contracts, calls);\n");
+    } else {
+        out.push_str(
+            "    for tx in blk.transactions() {\n\
+             \x20       for call in tx.calls.iter().filter(|call| contracts.contains(&call.address)) {\n",
+        );
+        for contract in &with_calls {
+            out.push_str(&format!(
+                "            map_{}_call(blk, tx, call, calls);\n",
+                contract.name
+            ));
+        }
+        out.push_str("        }\n    }\n");
+    }
+    out.push_str("}\n");
+
+    for contract in contracts {
+        out.push_str(&map_contract_event(contract));
+    }
+    for contract in with_calls {
+        out.push_str(&map_contract_call(contract));
+    }
+    out.push_str(&selector_tables(contracts));
+    out
+}
+
+// Selectors shared by several ABIs (e.g. `transferOwnership`) resolve to the first one.
+fn selector_tables(contracts: &[ContractAbi]) -> String {
+    let mut functions = BTreeMap::new();
+    let mut errors = BTreeMap::new();
+    for contract in contracts {
+        for (selector, name) in &contract.selectors {
+            functions.entry(*selector).or_insert(name);
+        }
+        for error in &contract.errors {
+            errors.entry(error.selector).or_insert(error);
+        }
+    }
+
+    let mut out = String::from(
+        "\n/// A custom error declared by one of the ABIs.\n\
+         pub struct AbiError {\n\
+         \x20   pub name: &'static str,\n\
+         \x20   pub inputs: Vec<(&'static str, ethabi::ParamType)>,\n\
+         }\n\n\
+         /// Name of the ABI function selected by the first four bytes of `input`.\n\
+         pub fn function_name(input: &[u8]) -> Option<&'static str> {\n\
+         \x20   match input.get(..4)? {\n",
+    );
+    for (selector, name) in functions {
+        out.push_str(&format!(
+            "        {} => Some(\"{}\"),\n",
+            selector_pattern(&selector),
+            name
+        ));
+    }
+    out.push_str(
+        "        _ => None,\n    }\n}\n\n\
+         /// The ABI custom error selected by the first four bytes of revert `data`.\n\
+         pub fn custom_error(data: &[u8]) -> Option<AbiError> {\n\
+         \x20   match data.get(..4)? {\n",
+    );
+    for (selector, error) in errors {
+        let inputs = error
+            .inputs
+            .iter()
+            .map(|(name, kind)| format!("(\"{}\", {})", name, param_type_rs(kind)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        out.push_str(&format!(
+            "        {} => Some(AbiError {{ name: \"{}\", inputs: vec![{}] }}),\n",
+            selector_pattern(&selector),
+            error.name,
+            inputs
+        ));
+    }
+    out.push_str("        _ => None,\n    }\n}\n");
+    out
+}
+
+fn selector_pattern(selector: &[u8; 4]) -> String {
+    let bytes = selector
+        .iter()
+        .map(|byte| format!("0x{:02x}", byte))
+        .collect::<Vec<_>>();
+    format!("[{}]", bytes.join(", "))
+}
+
+fn param_type_rs(kind: &ParamType) -> String {
+    match kind {
+        ParamType::Address => "ethabi::ParamType::Address".to_string(),
+        ParamType::Bytes => "ethabi::ParamType::Bytes".to_string(),
+        ParamType::Int(size) => format!("ethabi::ParamType::Int({})", size),
+        ParamType::Uint(size) => format!("ethabi::ParamType::Uint({})", size),
+        ParamType::Bool => "ethabi::ParamType::Bool".to_string(),
+        ParamType::String => "ethabi::ParamType::String".to_string(),
+        ParamType::FixedBytes(size) => format!("ethabi::ParamType::FixedBytes({})", size),
+        ParamType::Array(inner) => format!(
+            "ethabi::ParamType::Array(Box::new({}))",
+            param_type_rs(inner)
+        ),
+        ParamType::FixedArray(inner, size) => {
+            format!(
+                "ethabi::ParamType::FixedArray(Box::new({}), {})",
+                param_type_rs(inner),
+                size
+            )
+        }
+        ParamType::Tuple(kinds) => {
+            let kinds = kinds.iter().map(param_type_rs).collect::<Vec<_>>();
+            format!("ethabi::ParamType::Tuple(vec![{}])", kinds.join(", "))
+        }
+    }
+}
+
+fn map_contract_event(contract: &ContractAbi) -> String {
+    let mut out = String::from("\n");
+    for event in &contract.events {
+        let topic = event.topic.expect("events have a topic");
+        out.push_str(&format!(
+            "const {}: [u8; 32] = hex!(\"{}\");\n",
+            topic_const(contract, event),
+            topic
+                .iter()
+                .map(|byte| format!("{:02x}", byte))
+                .collect::<String>()
+        ));
+    }
+    out.push_str(&format!(
+        "\n/// Decodes `log` with the {} ABI event matching its signature and appends it to `events`.\n\
+         /// Returns `false` when the log is not one of its events.\n\
+         pub fn map_{}_event(blk: &eth::Block, trx: &eth::TransactionTrace, log: &eth::Log, events: &mut contract::Events) -> bool {{\n",
+        contract.name, contract.name
+    ));
+    if contract.events.is_empty() {
+        out.push_str("    let _ = (blk, trx, log, events);\n    false\n}\n");
+        return out;
+    }
+    out.push_str("    match topic0(log) {\n");
+    for event in &contract.events {
+        out.push_str(&event_arm(contract, event));
+    }
+    out.push_str("        _ => false,\n    }\n}\n");
+
+    for event in &contract.events {
+        out.push_str(&format!(
+            "\nfn map_{}_{}(blk: &eth::Block, trx: &eth::TransactionTrace, log: &eth::Log, events: &mut contract::Events) -> bool {{\n\
+             \x20   let Some(event) = abi::{}_contract::events::{}::match_and_decode(log) else {{ return false; }};\n",
+            contract.name, event.list_name, contract.name, event.rust_name
+        ));
+        out.push_str(&checked_fields(
+            &event.fields,
+            "event",
+            &Position {
+                item: format!("{} {}", contract.name, event.rust_name),
+                format: "tx {} log {}",
+                args: "Hex(&trx.hash), log.block_index",
+            },
+            "return false;",
+            "    ",
+        ));
+        out.push_str(&format!(
+            "    events.{}.push(contract::{} {{\n",
+            event_list(&contract.name, event),
+            rust_message_name(&contract.name, event, "")
+        ));
+        out.push_str(
+            "        evt_tx_hash: Hex(&trx.hash).to_string(),\n\
+             \x20       evt_index: log.block_index,\n\
+             \x20       evt_block_time: Some(blk.timestamp().to_owned()),\n\
+             \x20       evt_block_number: blk.number,\n\
+             \x20       contract_address: log.address.clone(),\n\
+             \x20       evt_context: Some(crate::event_context(blk, trx, log)),\n",
+        );
+        for field in &event.fields {
+            out.push_str(&format!("        {},\n", field_value(field, "event")));
+        }
+        out.push_str(&bigint_fields(&event.fields, "event", "        "));
+        if !event.derived.is_empty() {
+            out.push_str("        ..Default::default()\n");
+        }
+        out.push_str("    });\n    true\n}\n");
+    }
+    out
+}
+
+fn event_arm(contract: &ContractAbi, event: &Item) -> String {
+    format!(
+        "        Some({}) => map_{}_{}(blk, trx, log, events),\n",
+        topic_const(contract, event),
+        contract.name,
+        event.list_name
+    )
+}
+
+fn topic_const(contract: &ContractAbi, event: &Item) -> String {
+    format!(
+        "{}_{}",
+        contract.name.to_uppercase(),
+        event.list_name.to_uppercase()
+    )
+}
+
+fn map_contract_call(contract: &ContractAbi) -> String {
+    let mut out = format!(
+        "\n// Decodes `call` with the {} ABI function matching its selector and appends it to `calls`.\n\
+         fn map_{}_call(blk: &eth::Block, tx: &eth::TransactionTrace, call: &eth::Call, calls: &mut contract::Calls) {{\n",
+        contract.name, contract.name
+    );
+    for (i, call) in contract.calls.iter().enumerate() {
+        let function = format!(
+            "abi::{}_contract::functions::{}",
+            contract.name, call.rust_name
+        );
+        let condition = if call.fields.is_empty() {
+            format!("{}::match_and_decode(call).is_some()", function)
+        } else {
+            format!(
+                "let Some(decoded_call) = {}::match_and_decode(call)",
+                function
+            )
+        };
+        out.push_str(&format!(
+            "    {}if {} {{\n",
+            if i == 0 { "" } else { "} else " },
+            condition
+        ));
+        out.push_str(&checked_fields(
+            &call.fields,
+            "decoded_call",
+            &Position {
+                item: format!("{} call {}", contract.name, call.rust_name),
+                format: "tx {} ordinal {}",
+                args: "Hex(&tx.hash), call.begin_ordinal",
+            },
+            "return;",
+            "        ",
+        ));
+        out.push_str(&format!(
+            "        calls.{}.push(contract::{} {{\n",
+            call_list(&contract.name, call),
+            rust_message_name(&contract.name, call, "Call")
+        ));
+        out.push_str(
+            "            call_tx_hash: Hex(&tx.hash).to_string(),\n\
+             \x20           call_block_time: Some(blk.timestamp().to_owned()),\n\
+             \x20           call_block_number: blk.number,\n\
+             \x20           call_ordinal: call.begin_ordinal,\n\
+             \x20           call_success: !call.state_reverted,\n\
+             \x20           call_caller: call.caller.clone(),\n\
+             \x20           call_value: crate::call_value(call),\n\
+             \x20           call_gas_used: call.gas_consumed,\n\
+             \x20           contract_address: call.address.clone(),\n",
+        );
+        for field in &call.fields {
+            out.push_str(&format!(
+                "            {},\n",
+                field_value(field, "decoded_call")
+            ));
+        }
+        out.push_str(&bigint_fields(&call.fields, "decoded_call", "            "));
+        out.push_str("        });\n");
+    }
+    out.push_str("    }\n}\n");
+    out
+}
+
+// The `BigInt` companions only borrow the decoded value, so they can follow the moves above.
+fn bigint_fields(fields: &[Field], decoded: &str, indent: &str) -> String {
+    fields
+        .iter()
+        .filter(|field| field.bigint)
+        .map(|field| {
+            format!(
+                "{}{}_bigint: Some((&{}.{}).into()),\n",
+                indent, field.name, decoded, field.name
+            )
+        })
+        .collect()
+}
+
+fn write_if_changed(path: &str, contents: &str) -> Result<()> {
+    if fs::read_to_string(path).ok().as_deref() != Some(contents) {
+        fs::write(path, contents).with_context(|| format!("writing {}", path))?;
+    }
     Ok(())
 }
@@ -0,0 +1,282 @@
+CREATE TABLE IF NOT EXISTS nft_approval (
+    "evt_tx_hash" VARCHAR(64),
+    "evt_index" INT,
+    "evt_block_time" TIMESTAMP,
+    "evt_block_number" DECIMAL,
+    "evt_block_hash" VARCHAR(64),
+    "evt_tx_index" INT,
+    "evt_tx_from" VARCHAR(40),
+    "evt_tx_to" VARCHAR(40),
+    "evt_tx_nonce" DECIMAL,
+    "evt_tx_gas_used" DECIMAL,
+    "evt_tx_effective_gas_price" DECIMAL,
+    "evt_ordinal" DECIMAL,
+    "contract_address" VARCHAR(40),
+    "approved" VARCHAR(40),
+    "owner" VARCHAR(40),
+    "token_id" DECIMAL,
+    PRIMARY KEY(evt_tx_hash,evt_index)
+);
+CREATE TABLE IF NOT EXISTS nft_approval_for_all (
+    "evt_tx_hash" VARCHAR(64),
+    "evt_index" INT,
+    "evt_block_time" TIMESTAMP,
+    "evt_block_number" DECIMAL,
+    "evt_block_hash" VARCHAR(64),
+    "evt_tx_index" INT,
+    "evt_tx_from" VARCHAR(40),
+    "evt_tx_to" VARCHAR(40),
+    "evt_tx_nonce" DECIMAL,
+    "evt_tx_gas_used" DECIMAL,
+    "evt_tx_effective_gas_price" DECIMAL,
+    "evt_ordinal" DECIMAL,
+    "contract_address" VARCHAR(40),
+    "approved" BOOL,
+    "operator" VARCHAR(40),
+    "owner" VARCHAR(40),
+    PRIMARY KEY(evt_tx_hash,evt_index)
+);
+CREATE TABLE IF NOT EXISTS nft_batch_metadata_update (
+    "evt_tx_hash" VARCHAR(64),
+    "evt_index" INT,
+    "evt_block_time" TIMESTAMP,
+    "evt_block_number" DECIMAL,
+    "evt_block_hash" VARCHAR(64),
+    "evt_tx_index" INT,
+    "evt_tx_from" VARCHAR(40),
+    "evt_tx_to" VARCHAR(40),
+    "evt_tx_nonce" DECIMAL,
+    "evt_tx_gas_used" DECIMAL,
+    "evt_tx_effective_gas_price" DECIMAL,
+    "evt_ordinal" DECIMAL,
+    "contract_address" VARCHAR(40),
+    "u_from_token_id" DECIMAL,
+    "u_to_token_id" DECIMAL,
+    PRIMARY KEY(evt_tx_hash,evt_index)
+);
+CREATE TABLE IF NOT EXISTS nft_metadata_update (
+    "evt_tx_hash" VARCHAR(64),
+    "evt_index" INT,
+    "evt_block_time" TIMESTAMP,
+    "evt_block_number" DECIMAL,
+    "evt_block_hash" VARCHAR(64),
+    "evt_tx_index" INT,
+    "evt_tx_from" VARCHAR(40),
+    "evt_tx_to" VARCHAR(40),
+    "evt_tx_nonce" DECIMAL,
+    "evt_tx_gas_used" DECIMAL,
+    "evt_tx_effective_gas_price" DECIMAL,
+    "evt_ordinal" DECIMAL,
+    "contract_address" VARCHAR(40),
+    "u_token_id" DECIMAL,
+    PRIMARY KEY(evt_tx_hash,evt_index)
+);
+CREATE TABLE IF NOT EXISTS nft_role_admin_changed (
+    "evt_tx_hash" VARCHAR(64),
+    "evt_index" INT,
+    "evt_block_time" TIMESTAMP,
+    "evt_block_number" DECIMAL,
+    "evt_block_hash" VARCHAR(64),
+    "evt_tx_index" INT,
+    "evt_tx_from" VARCHAR(40),
+    "evt_tx_to" VARCHAR(40),
+    "evt_tx_nonce" DECIMAL,
+    "evt_tx_gas_used" DECIMAL,
+    "evt_tx_effective_gas_price" DECIMAL,
+    "evt_ordinal" DECIMAL,
+    "contract_address" VARCHAR(40),
+    "new_admin_role" VARCHAR(64),
+    "previous_admin_role" VARCHAR(64),
+    "role" VARCHAR(64),
+    PRIMARY KEY(evt_tx_hash,evt_index)
+);
+CREATE TABLE IF NOT EXISTS nft_role_granted (
+    "evt_tx_hash" VARCHAR(64),
+    "evt_index" INT,
+    "evt_block_time" TIMESTAMP,
+    "evt_block_number" DECIMAL,
+    "evt_block_hash" VARCHAR(64),
+    "evt_tx_index" INT,
+    "evt_tx_from" VARCHAR(40),
+    "evt_tx_to" VARCHAR(40),
+    "evt_tx_nonce" DECIMAL,
+    "evt_tx_gas_used" DECIMAL,
+    "evt_tx_effective_gas_price" DECIMAL,
+    "evt_ordinal" DECIMAL,
+    "contract_address" VARCHAR(40),
+    "account" VARCHAR(40),
+    "role" VARCHAR(64),
+    "sender" VARCHAR(40),
+    PRIMARY KEY(evt_tx_hash,evt_index)
+);
+CREATE TABLE IF NOT EXISTS nft_role_revoked (
+    "evt_tx_hash" VARCHAR(64),
+    "evt_index" INT,
+    "evt_block_time" TIMESTAMP,
+    "evt_block_number" DECIMAL,
+    "evt_block_hash" VARCHAR(64),
+    "evt_tx_index" INT,
+    "evt_tx_from" VARCHAR(40),
+    "evt_tx_to" VARCHAR(40),
+    "evt_tx_nonce" DECIMAL,
+    "evt_tx_gas_used" DECIMAL,
+    "evt_tx_effective_gas_price" DECIMAL,
+    "evt_ordinal" DECIMAL,
+    "contract_address" VARCHAR(40),
+    "account" VARCHAR(40),
+    "role" VARCHAR(64),
+    "sender" VARCHAR(40),
+    PRIMARY KEY(evt_tx_hash,evt_index)
+);
+CREATE TABLE IF NOT EXISTS nft_transfer (
+    "evt_tx_hash" VARCHAR(64),
+    "evt_index" INT,
+    "evt_block_time" TIMESTAMP,
+    "evt_block_number" DECIMAL,
+    "evt_block_hash" VARCHAR(64),
+    "evt_tx_index" INT,
+    "evt_tx_from" VARCHAR(40),
+    "evt_tx_to" VARCHAR(40),
+    "evt_tx_nonce" DECIMAL,
+    "evt_tx_gas_used" DECIMAL,
+    "evt_tx_effective_gas_price" DECIMAL,
+    "evt_ordinal" DECIMAL,
+    "contract_address" VARCHAR(40),
+    "from" VARCHAR(40),
+    "to" VARCHAR(40),
+    "token_id" DECIMAL,
+    PRIMARY KEY(evt_tx_hash,evt_index)
+);
+CREATE TABLE IF NOT EXISTS attester_attested (
+    "evt_tx_hash" VARCHAR(64),
+    "evt_index" INT,
+    "evt_block_time" TIMESTAMP,
+    "evt_block_number" DECIMAL,
+    "evt_block_hash" VARCHAR(64),
+    "evt_tx_index" INT,
+    "evt_tx_from" VARCHAR(40),
+    "evt_tx_to" VARCHAR(40),
+    "evt_tx_nonce" DECIMAL,
+    "evt_tx_gas_used" DECIMAL,
+    "evt_tx_effective_gas_price" DECIMAL,
+    "evt_ordinal" DECIMAL,
+    "contract_address" VARCHAR(40),
+    "attester" TEXT,
+    "owner" VARCHAR(40),
+    "request_id" VARCHAR(64),
+    "url" TEXT,
+    PRIMARY KEY(evt_tx_hash,evt_index)
+);
+CREATE TABLE IF NOT EXISTS attester_ownership_transfer_requested (
+    "evt_tx_hash" VARCHAR(64),
+    "evt_index" INT,
+    "evt_block_time" TIMESTAMP,
+    "evt_block_number" DECIMAL,
+    "evt_block_hash" VARCHAR(64),
+    "evt_tx_index" INT,
+    "evt_tx_from" VARCHAR(40),
+    "evt_tx_to" VARCHAR(40),
+    "evt_tx_nonce" DECIMAL,
+    "evt_tx_gas_used" DECIMAL,
+    "evt_tx_effective_gas_price" DECIMAL,
+    "evt_ordinal" DECIMAL,
+    "contract_address" VARCHAR(40),
+    "from" VARCHAR(40),
+    "to" VARCHAR(40),
+    PRIMARY KEY(evt_tx_hash,evt_index)
+);
+CREATE TABLE IF NOT EXISTS attester_ownership_transferred (
+    "evt_tx_hash" VARCHAR(64),
+    "evt_index" INT,
+    "evt_block_time" TIMESTAMP,
+    "evt_block_number" DECIMAL,
+    "evt_block_hash" VARCHAR(64),
+    "evt_tx_index" INT,
+    "evt_tx_from" VARCHAR(40),
+    "evt_tx_to" VARCHAR(40),
+    "evt_tx_nonce" DECIMAL,
+    "evt_tx_gas_used" DECIMAL,
+    "evt_tx_effective_gas_price" DECIMAL,
+    "evt_ordinal" DECIMAL,
+    "contract_address" VARCHAR(40),
+    "from" VARCHAR(40),
+    "to" VARCHAR(40),
+    PRIMARY KEY(evt_tx_hash,evt_index)
+);
+CREATE TABLE IF NOT EXISTS attester_request_fulfilled (
+    "evt_tx_hash" VARCHAR(64),
+    "evt_index" INT,
+    "evt_block_time" TIMESTAMP,
+    "evt_block_number" DECIMAL,
+    "evt_block_hash" VARCHAR(64),
+    "evt_tx_index" INT,
+    "evt_tx_from" VARCHAR(40),
+    "evt_tx_to" VARCHAR(40),
+    "evt_tx_nonce" DECIMAL,
+    "evt_tx_gas_used" DECIMAL,
+    "evt_tx_effective_gas_price" DECIMAL,
+    "evt_ordinal" DECIMAL,
+    "contract_address" VARCHAR(40),
+    "id" VARCHAR(64),
+    PRIMARY KEY(evt_tx_hash,evt_index)
+);
+CREATE TABLE IF NOT EXISTS attester_request_sent (
+    "evt_tx_hash" VARCHAR(64),
+    "evt_index" INT,
+    "evt_block_time" TIMESTAMP,
+    "evt_block_number" DECIMAL,
+    "evt_block_hash" VARCHAR(64),
+    "evt_tx_index" INT,
+    "evt_tx_from" VARCHAR(40),
+    "evt_tx_to" VARCHAR(40),
+    "evt_tx_nonce" DECIMAL,
+    "evt_tx_gas_used" DECIMAL,
+    "evt_tx_effective_gas_price" DECIMAL,
+    "evt_ordinal" DECIMAL,
+    "contract_address" VARCHAR(40),
+    "id" VARCHAR(64),
+    PRIMARY KEY(evt_tx_hash,evt_index)
+);
+CREATE TABLE IF NOT EXISTS attester_response (
+    "evt_tx_hash" VARCHAR(64),
+    "evt_index" INT,
+    "evt_block_time" TIMESTAMP,
+    "evt_block_number" DECIMAL,
+    "evt_block_hash" VARCHAR(64),
+    "evt_tx_index" INT,
+    "evt_tx_from" VARCHAR(40),
+    "evt_tx_to" VARCHAR(40),
+    "evt_tx_nonce" DECIMAL,
+    "evt_tx_gas_used" DECIMAL,
+    "evt_tx_effective_gas_price" DECIMAL,
+    "evt_ordinal" DECIMAL,
+    "contract_address" VARCHAR(40),
+    "err" TEXT,
+    "request_id" VARCHAR(64),
+    "response" TEXT,
+    "response_text" TEXT,
+    "error_text" TEXT,
+    "is_error" BOOL,
+    PRIMARY KEY(evt_tx_hash,evt_index)
+);
+
+CREATE TABLE IF NOT EXISTS tokens (
+    "contract_address" VARCHAR(40),
+    "token_id" DECIMAL,
+    "owner" VARCHAR(40),
+    "minted_tx_hash" VARCHAR(64),
+    "minted_block_number" DECIMAL,
+    "minted_at" TIMESTAMP,
+    "burned" BOOL,
+    PRIMARY KEY(contract_address,token_id)
+);
+CREATE TABLE IF NOT EXISTS attestations (
+    "request_id" VARCHAR(64) PRIMARY KEY,
+    "owner" VARCHAR(40),
+    "attester" TEXT,
+    "url" TEXT,
+    "contract_address" VARCHAR(40),
+    "tx_hash" VARCHAR(64),
+    "block_number" DECIMAL,
+    "attested_at" TIMESTAMP
+);
@@ -1,3 +1,3 @@
-
-pub mod nft_contract;
 pub mod attester_contract;
+pub mod nft_contract;
+pub mod router_contract;
@@ -0,0 +1,92 @@
+use crate::numeric;
+use crate::pb::contract::v1 as contract;
+use crate::ZERO_ADDRESS;
+use substreams::store::{Appender, StoreGet, StoreSet};
+use substreams::Hex;
+
+/// Records the approved address of every token. ERC-721 clears a token's approval on every
+/// transfer, so transfers remove the key as approvals to the zero address do; events are
+/// replayed in log order so that an approval granted after a transfer in the same block sticks.
+pub fn set_token_approvals(events: &contract::Events, store: &impl StoreSet<String>) {
+    let approvals = events.nft_approvals.iter().map(|evt| {
+        (
+            evt.evt_index,
+            &evt.contract_address,
+            &evt.token_id_bigint,
+            Some(&evt.approved),
+        )
+    });
+    let transfers = events.nft_transfers.iter().map(|evt| {
+        (
+            evt.evt_index,
+            &evt.contract_address,
+            &evt.token_id_bigint,
+            None,
+        )
+    });
+
+    let mut changes = approvals.chain(transfers).collect::<Vec<_>>();
+    changes.sort_by_key(|(evt_index, ..)| *evt_index);
+    for (evt_index, contract, token_id, approved) in changes {
+        let key = token_approval_key(contract, token_id);
+        match approved {
+            Some(approved) if approved != &ZERO_ADDRESS => {
+                store.set(evt_index as u64, key, &Hex(approved).to_string())
+            }
+            _ => store.delete_prefix(evt_index as i64, &key),
+        }
+    }
+}
+
+/// Appends every `ApprovalForAll` to the owner's operator log: `+<operator>` when granted and
+/// `-<operator>` when revoked.
+pub fn append_operator_changes(events: &contract::Events, store: &impl Appender<String>) {
+    for evt in &events.nft_approval_for_alls {
+        let sign = if evt.approved { '+' } else { '-' };
+        store.append(
+            evt.evt_index as u64,
+            operators_key(&evt.contract_address, &evt.owner),
+            format!("{}{}", sign, Hex(&evt.operator)),
+        );
+    }
+}
+
+/// The operators each owner touched in this block has approved, after the block's changes.
+pub fn operator_approvals(
+    events: &contract::Events,
+    operators: &impl StoreGet<Vec<String>>,
+) -> contract::OperatorApprovals {
+    let mut approvals: Vec<contract::OperatorApproval> = Vec::new();
+    for evt in &events.nft_approval_for_alls {
+        if approvals.iter().any(|approval| {
+            approval.contract_address == evt.contract_address && approval.owner == evt.owner
+        }) {
+            continue;
+        }
+
+        let key = operators_key(&evt.contract_address, &evt.owner);
+        approvals.push(contract::OperatorApproval {
+            contract_address: evt.contract_address.clone(),
+            owner: evt.owner.clone(),
+            operators: crate::roles::current_members(operators.get_last(key).unwrap_or_default()),
+            block_number: evt.evt_block_number,
+            block_time: evt.evt_block_time,
+        });
+    }
+
+    contract::OperatorApprovals { approvals }
+}
+
+// Same layout as the owner key, with a trailing `:approved` so that `delete_prefix` only
+// matches this token.
+fn token_approval_key(contract_address: &[u8], token_id: &Option<contract::BigInt>) -> String {
+    format!(
+        "token:{}:{}:approved",
+        Hex(contract_address),
+        numeric::sortable_key(token_id)
+    )
+}
+
+fn operators_key(contract_address: &[u8], owner: &[u8]) -> String {
+    format!("account:{}:{}:operators", Hex(contract_address), Hex(owner))
+}
@@ -0,0 +1,53 @@
+//! The layout `map_events` replaced, kept only in `bench-baseline` builds so that
+//! `benches/map_events_wasm.rs` can count the WASM instructions of both layouts.
+
+use crate::abi::{attester_contract, nft_contract};
+use crate::mapping;
+use crate::oracle;
+use crate::params::TrackedContracts;
+use crate::pb::contract::v1 as contract;
+use substreams_ethereum::pb::eth::v2 as eth;
+use substreams_ethereum::Event;
+
+#[substreams::handlers::map]
+fn map_events_per_type_scans(
+    params: String,
+    blk: eth::Block,
+) -> Result<contract::Events, substreams::errors::Error> {
+    let contracts = TrackedContracts::parse(&params, "evt_addr")?;
+    let mut events = contract::Events::default();
+    scan::<nft_contract::events::Approval>(&blk, &contracts, &mut events);
+    scan::<nft_contract::events::ApprovalForAll>(&blk, &contracts, &mut events);
+    scan::<nft_contract::events::BatchMetadataUpdate>(&blk, &contracts, &mut events);
+    scan::<nft_contract::events::MetadataUpdate>(&blk, &contracts, &mut events);
+    scan::<nft_contract::events::RoleAdminChanged>(&blk, &contracts, &mut events);
+    scan::<nft_contract::events::RoleGranted>(&blk, &contracts, &mut events);
+    scan::<nft_contract::events::RoleRevoked>(&blk, &contracts, &mut events);
+    scan::<nft_contract::events::Transfer>(&blk, &contracts, &mut events);
+    scan::<attester_contract::events::Attested>(&blk, &contracts, &mut events);
+    scan::<attester_contract::events::OwnershipTransferRequested>(&blk, &contracts, &mut events);
+    scan::<attester_contract::events::OwnershipTransferred>(&blk, &contracts, &mut events);
+    scan::<attester_contract::events::RequestFulfilled>(&blk, &contracts, &mut events);
+    scan::<attester_contract::events::RequestSent>(&blk, &contracts, &mut events);
+    scan::<attester_contract::events::Response>(&blk, &contracts, &mut events);
+    oracle::decode_responses(&mut events.attester_responses);
+    Ok(events)
+}
+
+/// One full receipt scan for a single event type. `match_log` rejects the other logs as the
+/// per-type `match_and_decode` did, and the matches go through the generated mapping so both
+/// layouts produce the same `Events`.
+fn scan<E: Event>(blk: &eth::Block, contracts: &TrackedContracts, events: &mut contract::Events) {
+    for view in blk.receipts() {
+        for log in view
+            .receipt
+            .logs
+            .iter()
+            .filter(|log| contracts.contains(&log.address))
+        {
+            if E::match_log(log) {
+                mapping::map_event(blk, view.transaction, log, events);
+            }
+        }
+    }
+}
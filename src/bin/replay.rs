@@ -0,0 +1,111 @@
+//! Runs the modules over a directory of recorded blocks, without a Substreams endpoint, and
+//! prints their outputs as JSON lines.
+//!
+//! ```bash
+//! cargo run --features replay --bin replay -- [--manifest path] [-p module=params]... [-m module]... <blocks dir>
+//! ```
+//!
+//! Every `*.pb` file of the directory is a protobuf-encoded `sf.ethereum.type.v2.Block`; the
+//! blocks are processed in block number order. Params default to the `params` section of the
+//! manifest, `./substreams.yaml` unless `--manifest` is given, and `-m` limits the output to the
+//! given modules. The modules' `blockFilter`s are not applied: every block is processed.
+
+use miracam::replay::{Replay, MODULES};
+use prost::Message;
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use substreams::errors::Error;
+use substreams_ethereum::pb::eth::v2 as eth;
+
+const USAGE: &str =
+    "usage: replay [--manifest path] [-p module=params]... [-m module]... <blocks dir>";
+
+fn main() -> Result<(), Error> {
+    let mut manifest = PathBuf::from("substreams.yaml");
+    let mut overrides: Vec<(String, String)> = Vec::new();
+    let mut modules: Vec<String> = Vec::new();
+    let mut blocks_dir: Option<PathBuf> = None;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--manifest" => manifest = PathBuf::from(args.next().ok_or_else(|| Error::msg(USAGE))?),
+            "-p" => {
+                let param = args.next().ok_or_else(|| Error::msg(USAGE))?;
+                let (module, value) = param.split_once('=').ok_or_else(|| {
+                    Error::msg(format!("invalid param {:?}: expected module=params", param))
+                })?;
+                overrides.push((module.to_string(), value.to_string()));
+            }
+            "-m" => {
+                let module = args.next().ok_or_else(|| Error::msg(USAGE))?;
+                if !MODULES.contains(&module.as_str()) {
+                    return Err(Error::msg(format!(
+                        "unknown module {:?}, expected one of {}",
+                        module,
+                        MODULES.join(", ")
+                    )));
+                }
+                modules.push(module);
+            }
+            _ if blocks_dir.is_none() && !arg.starts_with('-') => {
+                blocks_dir = Some(PathBuf::from(arg))
+            }
+            _ => return Err(Error::msg(USAGE)),
+        }
+    }
+    let blocks_dir = blocks_dir.ok_or_else(|| Error::msg(USAGE))?;
+    let mut params = manifest_params(&manifest)?;
+    params.extend(overrides);
+
+    let replay = Replay::new(&params)?;
+    let mut stdout = io::stdout().lock();
+    for blk in read_blocks(&blocks_dir)? {
+        for line in replay.process(&blk)? {
+            if modules.is_empty()
+                || modules
+                    .iter()
+                    .any(|module| line["@module"] == module.as_str())
+            {
+                writeln!(stdout, "{}", line)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn read_blocks(dir: &Path) -> Result<Vec<eth::Block>, Error> {
+    let mut blocks = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().is_none_or(|extension| extension != "pb") {
+            continue;
+        }
+        let blk = eth::Block::decode(fs::read(&path)?.as_slice()).map_err(|err| {
+            Error::msg(format!(
+                "{}: not an sf.ethereum.type.v2.Block: {}",
+                path.display(),
+                err
+            ))
+        })?;
+        blocks.push(blk);
+    }
+    blocks.sort_by_key(|blk| blk.number);
+    Ok(blocks)
+}
+
+// The `params` section of the manifest, by module name.
+fn manifest_params(manifest: &Path) -> Result<HashMap<String, String>, Error> {
+    let invalid =
+        |err: &dyn std::fmt::Display| Error::msg(format!("{}: {}", manifest.display(), err));
+    let yaml: serde_yaml::Value =
+        serde_yaml::from_str(&fs::read_to_string(manifest).map_err(|err| invalid(&err))?)
+            .map_err(|err| invalid(&err))?;
+    match yaml.get("params") {
+        Some(params) => serde_yaml::from_value(params.clone()).map_err(|err| invalid(&err)),
+        None => Ok(HashMap::new()),
+    }
+}
@@ -0,0 +1,206 @@
+use crate::pb::contract::v1 as contract;
+use prost_types::Timestamp;
+use substreams::scalar::BigInt;
+use substreams::store::{StoreAdd, StoreGet, StoreSet};
+use substreams::Hex;
+
+const SENT: &str = "sent";
+const FULFILLED: &str = "fulfilled";
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Records what the transactions of each request cost in gas: the `sendRequest` transaction that
+/// emitted `RequestSent`, and the DON's fulfilment transaction that emitted `Response`.
+pub fn set_transaction_costs(
+    events: &contract::Events,
+    store: &impl StoreSet<contract::TransactionCost>,
+) {
+    for (ordinal, request_id, step, cost) in request_costs(events) {
+        store.set(ordinal, transaction_cost_key(request_id, step), &cost);
+    }
+}
+
+/// Sums the gas spent on requests, and counts requests and attestations, per UTC day. Dividing a
+/// day's `send_cost_wei` and `fulfillment_cost_wei` by its `attestations` gives the cost per
+/// attested photo. Requests only add their share of a transaction, so each one counts once.
+pub fn add_daily_costs(events: &contract::Events, store: &impl StoreAdd<BigInt>) {
+    for (ordinal, _, step, cost) in request_costs(events) {
+        let day = utc_day(&cost.block_time);
+        let cost_wei = cost
+            .cost_wei
+            .parse::<BigInt>()
+            .unwrap_or_else(|_| BigInt::zero());
+        if step == SENT {
+            store.add(ordinal, daily_key(&day, "send_cost_wei"), cost_wei);
+            store.add(ordinal, daily_key(&day, "requests"), BigInt::one());
+        } else {
+            store.add(ordinal, daily_key(&day, "fulfillment_cost_wei"), cost_wei);
+        }
+    }
+    for evt in &events.attester_attesteds {
+        let day = utc_day(&evt.evt_block_time);
+        store.add(
+            evt.evt_index as u64,
+            daily_key(&day, "attestations"),
+            BigInt::one(),
+        );
+    }
+}
+
+/// The cost so far of every request sent or fulfilled in this block.
+pub fn attestation_costs(
+    events: &contract::Events,
+    store: &impl StoreGet<contract::TransactionCost>,
+) -> contract::AttestationCosts {
+    let mut request_ids: Vec<&Vec<u8>> = Vec::new();
+    let candidates = events
+        .attester_request_sents
+        .iter()
+        .map(|evt| &evt.id)
+        .chain(events.attester_responses.iter().map(|evt| &evt.request_id));
+    for request_id in candidates {
+        if !request_ids.contains(&request_id) {
+            request_ids.push(request_id);
+        }
+    }
+
+    let costs = request_ids
+        .into_iter()
+        .map(|request_id| {
+            let sent = store.get_last(transaction_cost_key(request_id, SENT));
+            let fulfilled = store.get_last(transaction_cost_key(request_id, FULFILLED));
+            let total_cost_wei = [&sent, &fulfilled]
+                .into_iter()
+                .flatten()
+                .map(|cost| {
+                    cost.cost_wei
+                        .parse::<BigInt>()
+                        .unwrap_or_else(|_| BigInt::zero())
+                })
+                .fold(BigInt::zero(), |total, cost| total + cost);
+
+            contract::AttestationCost {
+                request_id: request_id.clone(),
+                sent,
+                fulfilled,
+                total_cost_wei: total_cost_wei.to_string(),
+            }
+        })
+        .collect();
+
+    contract::AttestationCosts { costs }
+}
+
+// A `RequestSent` or `Response`: the `SENT` or `FULFILLED` step of a request.
+struct RequestStep<'a> {
+    evt_index: u32,
+    request_id: &'a Vec<u8>,
+    step: &'static str,
+    tx_hash: &'a str,
+    block_number: u64,
+    block_time: &'a Option<Timestamp>,
+    context: &'a Option<contract::EventContext>,
+}
+
+// The cost of every request step of the block, with its ordinal, request id and step. The steps
+// of one kind in one transaction split its gas cost between them.
+fn request_costs(
+    events: &contract::Events,
+) -> Vec<(u64, &Vec<u8>, &'static str, contract::TransactionCost)> {
+    let sent = events.attester_request_sents.iter().map(|evt| RequestStep {
+        evt_index: evt.evt_index,
+        request_id: &evt.id,
+        step: SENT,
+        tx_hash: &evt.evt_tx_hash,
+        block_number: evt.evt_block_number,
+        block_time: &evt.evt_block_time,
+        context: &evt.evt_context,
+    });
+    let fulfilled = events.attester_responses.iter().map(|evt| RequestStep {
+        evt_index: evt.evt_index,
+        request_id: &evt.request_id,
+        step: FULFILLED,
+        tx_hash: &evt.evt_tx_hash,
+        block_number: evt.evt_block_number,
+        block_time: &evt.evt_block_time,
+        context: &evt.evt_context,
+    });
+    let steps = sent.chain(fulfilled).collect::<Vec<_>>();
+
+    steps
+        .iter()
+        .map(|request| {
+            let in_tx = steps
+                .iter()
+                .filter(|other| other.step == request.step && other.tx_hash == request.tx_hash)
+                .collect::<Vec<_>>();
+            let (share, remainder) =
+                gas_cost(request.context).div_rem(&BigInt::from(in_tx.len() as u64));
+            let first = in_tx
+                .iter()
+                .all(|other| other.evt_index >= request.evt_index);
+
+            let context = request.context.clone().unwrap_or_default();
+            let cost = contract::TransactionCost {
+                tx_hash: request.tx_hash.to_string(),
+                block_number: request.block_number,
+                block_time: *request.block_time,
+                gas_used: context.tx_gas_used,
+                effective_gas_price: context.tx_effective_gas_price,
+                cost_wei: if first { share + remainder } else { share }.to_string(),
+                requests_in_tx: in_tx.len() as u32,
+            };
+            (
+                request.evt_index as u64,
+                request.request_id,
+                request.step,
+                cost,
+            )
+        })
+        .collect()
+}
+
+// Gas used times the effective gas price, in wei.
+fn gas_cost(context: &Option<contract::EventContext>) -> BigInt {
+    match context {
+        Some(context) => {
+            let price = context
+                .tx_effective_gas_price
+                .parse::<BigInt>()
+                .unwrap_or_else(|_| BigInt::zero());
+            price * BigInt::from(context.tx_gas_used)
+        }
+        None => BigInt::zero(),
+    }
+}
+
+/// The UTC date of `block_time`, as `YYYY-MM-DD`.
+pub fn utc_day(block_time: &Option<Timestamp>) -> String {
+    let seconds = block_time.map(|time| time.seconds).unwrap_or_default();
+    // Days since 1970-01-01 to a proleptic Gregorian date, after Howard Hinnant's
+    // `civil_from_days`.
+    let days = seconds.div_euclid(SECONDS_PER_DAY) + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+fn transaction_cost_key(request_id: &[u8], step: &str) -> String {
+    format!("cost:{}:{}", Hex(request_id), step)
+}
+
+fn daily_key(day: &str, total: &str) -> String {
+    format!("day:{}:{}", day, total)
+}
@@ -0,0 +1,314 @@
+use crate::numeric;
+use crate::pb::contract::v1 as contract;
+use crate::ZERO_ADDRESS;
+use substreams::Hex;
+use substreams_database_change::tables::{Row, Tables as DatabaseChangeTables};
+
+pub fn db_nft_out(events: &contract::Events, tables: &mut DatabaseChangeTables) {
+    // Loop over all the abis events to create table changes
+    events.nft_approvals.iter().for_each(|evt| {
+        let row = tables
+            .create_row(
+                "nft_approval",
+                [
+                    ("evt_tx_hash", evt.evt_tx_hash.to_string()),
+                    ("evt_index", evt.evt_index.to_string()),
+                ],
+            )
+            .set("evt_block_time", evt.evt_block_time.as_ref().unwrap())
+            .set("evt_block_number", evt.evt_block_number)
+            .set("contract_address", Hex(&evt.contract_address).to_string())
+            .set("approved", Hex(&evt.approved).to_string())
+            .set("owner", Hex(&evt.owner).to_string())
+            .set("token_id", numeric::big_int(&evt.token_id_bigint));
+        set_event_context(row, &evt.evt_context);
+    });
+    events.nft_approval_for_alls.iter().for_each(|evt| {
+        let row = tables
+            .create_row(
+                "nft_approval_for_all",
+                [
+                    ("evt_tx_hash", evt.evt_tx_hash.to_string()),
+                    ("evt_index", evt.evt_index.to_string()),
+                ],
+            )
+            .set("evt_block_time", evt.evt_block_time.as_ref().unwrap())
+            .set("evt_block_number", evt.evt_block_number)
+            .set("contract_address", Hex(&evt.contract_address).to_string())
+            .set("approved", evt.approved)
+            .set("operator", Hex(&evt.operator).to_string())
+            .set("owner", Hex(&evt.owner).to_string());
+        set_event_context(row, &evt.evt_context);
+    });
+    events.nft_batch_metadata_updates.iter().for_each(|evt| {
+        let row = tables
+            .create_row(
+                "nft_batch_metadata_update",
+                [
+                    ("evt_tx_hash", evt.evt_tx_hash.to_string()),
+                    ("evt_index", evt.evt_index.to_string()),
+                ],
+            )
+            .set("evt_block_time", evt.evt_block_time.as_ref().unwrap())
+            .set("evt_block_number", evt.evt_block_number)
+            .set("contract_address", Hex(&evt.contract_address).to_string())
+            .set(
+                "u_from_token_id",
+                numeric::big_int(&evt.u_from_token_id_bigint),
+            )
+            .set("u_to_token_id", numeric::big_int(&evt.u_to_token_id_bigint));
+        set_event_context(row, &evt.evt_context);
+    });
+    events.nft_metadata_updates.iter().for_each(|evt| {
+        let row = tables
+            .create_row(
+                "nft_metadata_update",
+                [
+                    ("evt_tx_hash", evt.evt_tx_hash.to_string()),
+                    ("evt_index", evt.evt_index.to_string()),
+                ],
+            )
+            .set("evt_block_time", evt.evt_block_time.as_ref().unwrap())
+            .set("evt_block_number", evt.evt_block_number)
+            .set("contract_address", Hex(&evt.contract_address).to_string())
+            .set("u_token_id", numeric::big_int(&evt.u_token_id_bigint));
+        set_event_context(row, &evt.evt_context);
+    });
+    events.nft_role_admin_changeds.iter().for_each(|evt| {
+        let row = tables
+            .create_row(
+                "nft_role_admin_changed",
+                [
+                    ("evt_tx_hash", evt.evt_tx_hash.to_string()),
+                    ("evt_index", evt.evt_index.to_string()),
+                ],
+            )
+            .set("evt_block_time", evt.evt_block_time.as_ref().unwrap())
+            .set("evt_block_number", evt.evt_block_number)
+            .set("contract_address", Hex(&evt.contract_address).to_string())
+            .set("new_admin_role", Hex(&evt.new_admin_role).to_string())
+            .set(
+                "previous_admin_role",
+                Hex(&evt.previous_admin_role).to_string(),
+            )
+            .set("role", Hex(&evt.role).to_string());
+        set_event_context(row, &evt.evt_context);
+    });
+    events.nft_role_granteds.iter().for_each(|evt| {
+        let row = tables
+            .create_row(
+                "nft_role_granted",
+                [
+                    ("evt_tx_hash", evt.evt_tx_hash.to_string()),
+                    ("evt_index", evt.evt_index.to_string()),
+                ],
+            )
+            .set("evt_block_time", evt.evt_block_time.as_ref().unwrap())
+            .set("evt_block_number", evt.evt_block_number)
+            .set("contract_address", Hex(&evt.contract_address).to_string())
+            .set("account", Hex(&evt.account).to_string())
+            .set("role", Hex(&evt.role).to_string())
+            .set("sender", Hex(&evt.sender).to_string());
+        set_event_context(row, &evt.evt_context);
+    });
+    events.nft_role_revokeds.iter().for_each(|evt| {
+        let row = tables
+            .create_row(
+                "nft_role_revoked",
+                [
+                    ("evt_tx_hash", evt.evt_tx_hash.to_string()),
+                    ("evt_index", evt.evt_index.to_string()),
+                ],
+            )
+            .set("evt_block_time", evt.evt_block_time.as_ref().unwrap())
+            .set("evt_block_number", evt.evt_block_number)
+            .set("contract_address", Hex(&evt.contract_address).to_string())
+            .set("account", Hex(&evt.account).to_string())
+            .set("role", Hex(&evt.role).to_string())
+            .set("sender", Hex(&evt.sender).to_string());
+        set_event_context(row, &evt.evt_context);
+    });
+    events.nft_transfers.iter().for_each(|evt| {
+        let row = tables
+            .create_row(
+                "nft_transfer",
+                [
+                    ("evt_tx_hash", evt.evt_tx_hash.to_string()),
+                    ("evt_index", evt.evt_index.to_string()),
+                ],
+            )
+            .set("evt_block_time", evt.evt_block_time.as_ref().unwrap())
+            .set("evt_block_number", evt.evt_block_number)
+            .set("contract_address", Hex(&evt.contract_address).to_string())
+            .set("from", Hex(&evt.from).to_string())
+            .set("to", Hex(&evt.to).to_string())
+            .set("token_id", numeric::big_int(&evt.token_id_bigint));
+        set_event_context(row, &evt.evt_context);
+    });
+}
+
+pub fn db_attester_out(events: &contract::Events, tables: &mut DatabaseChangeTables) {
+    // Loop over all the abis events to create table changes
+    events.attester_attesteds.iter().for_each(|evt| {
+        let row = tables
+            .create_row(
+                "attester_attested",
+                [
+                    ("evt_tx_hash", evt.evt_tx_hash.to_string()),
+                    ("evt_index", evt.evt_index.to_string()),
+                ],
+            )
+            .set("evt_block_time", evt.evt_block_time.as_ref().unwrap())
+            .set("evt_block_number", evt.evt_block_number)
+            .set("contract_address", Hex(&evt.contract_address).to_string())
+            .set("attester", &evt.attester)
+            .set("owner", Hex(&evt.owner).to_string())
+            .set("request_id", Hex(&evt.request_id).to_string())
+            .set("url", &evt.url);
+        set_event_context(row, &evt.evt_context);
+    });
+    events
+        .attester_ownership_transfer_requesteds
+        .iter()
+        .for_each(|evt| {
+            let row = tables
+                .create_row(
+                    "attester_ownership_transfer_requested",
+                    [
+                        ("evt_tx_hash", evt.evt_tx_hash.to_string()),
+                        ("evt_index", evt.evt_index.to_string()),
+                    ],
+                )
+                .set("evt_block_time", evt.evt_block_time.as_ref().unwrap())
+                .set("evt_block_number", evt.evt_block_number)
+                .set("contract_address", Hex(&evt.contract_address).to_string())
+                .set("from", Hex(&evt.from).to_string())
+                .set("to", Hex(&evt.to).to_string());
+            set_event_context(row, &evt.evt_context);
+        });
+    events
+        .attester_ownership_transferreds
+        .iter()
+        .for_each(|evt| {
+            let row = tables
+                .create_row(
+                    "attester_ownership_transferred",
+                    [
+                        ("evt_tx_hash", evt.evt_tx_hash.to_string()),
+                        ("evt_index", evt.evt_index.to_string()),
+                    ],
+                )
+                .set("evt_block_time", evt.evt_block_time.as_ref().unwrap())
+                .set("evt_block_number", evt.evt_block_number)
+                .set("contract_address", Hex(&evt.contract_address).to_string())
+                .set("from", Hex(&evt.from).to_string())
+                .set("to", Hex(&evt.to).to_string());
+            set_event_context(row, &evt.evt_context);
+        });
+    events.attester_request_fulfilleds.iter().for_each(|evt| {
+        let row = tables
+            .create_row(
+                "attester_request_fulfilled",
+                [
+                    ("evt_tx_hash", evt.evt_tx_hash.to_string()),
+                    ("evt_index", evt.evt_index.to_string()),
+                ],
+            )
+            .set("evt_block_time", evt.evt_block_time.as_ref().unwrap())
+            .set("evt_block_number", evt.evt_block_number)
+            .set("contract_address", Hex(&evt.contract_address).to_string())
+            .set("id", Hex(&evt.id).to_string());
+        set_event_context(row, &evt.evt_context);
+    });
+    events.attester_request_sents.iter().for_each(|evt| {
+        let row = tables
+            .create_row(
+                "attester_request_sent",
+                [
+                    ("evt_tx_hash", evt.evt_tx_hash.to_string()),
+                    ("evt_index", evt.evt_index.to_string()),
+                ],
+            )
+            .set("evt_block_time", evt.evt_block_time.as_ref().unwrap())
+            .set("evt_block_number", evt.evt_block_number)
+            .set("contract_address", Hex(&evt.contract_address).to_string())
+            .set("id", Hex(&evt.id).to_string());
+        set_event_context(row, &evt.evt_context);
+    });
+    events.attester_responses.iter().for_each(|evt| {
+        let row = tables
+            .create_row(
+                "attester_response",
+                [
+                    ("evt_tx_hash", evt.evt_tx_hash.to_string()),
+                    ("evt_index", evt.evt_index.to_string()),
+                ],
+            )
+            .set("evt_block_time", evt.evt_block_time.as_ref().unwrap())
+            .set("evt_block_number", evt.evt_block_number)
+            .set("contract_address", Hex(&evt.contract_address).to_string())
+            .set("err", Hex(&evt.err).to_string())
+            .set("request_id", Hex(&evt.request_id).to_string())
+            .set("response", Hex(&evt.response).to_string())
+            .set("response_text", &evt.response_text)
+            .set("error_text", &evt.error_text)
+            .set("is_error", evt.is_error);
+        set_event_context(row, &evt.evt_context);
+    });
+}
+
+/// Derives the `tokens` table from transfers: a mint writes the row, later transfers
+/// move the owner and a burn flags the token as burned without dropping its history.
+/// Every change is an upsert, as a burned token id can be minted again and the row of
+/// its first mint is then already there, possibly within the same block.
+pub fn db_tokens_out(events: &contract::Events, tables: &mut DatabaseChangeTables) {
+    events.nft_transfers.iter().for_each(|evt| {
+        let row = tables
+            .upsert_row(
+                "tokens",
+                [
+                    ("contract_address", Hex(&evt.contract_address).to_string()),
+                    ("token_id", evt.token_id.to_string()),
+                ],
+            )
+            .set("owner", Hex(&evt.to).to_string())
+            .set("burned", evt.to == ZERO_ADDRESS);
+        if evt.from == ZERO_ADDRESS {
+            row.set("minted_tx_hash", &evt.evt_tx_hash)
+                .set("minted_block_number", evt.evt_block_number)
+                .set("minted_at", evt.evt_block_time.as_ref().unwrap());
+        }
+    });
+}
+
+/// Derives the `attestations` table, one row per Chainlink Functions request that ended
+/// in an `Attested` event.
+pub fn db_attestations_out(events: &contract::Events, tables: &mut DatabaseChangeTables) {
+    events.attester_attesteds.iter().for_each(|evt| {
+        tables
+            .create_row("attestations", Hex(&evt.request_id).to_string())
+            .set("owner", Hex(&evt.owner).to_string())
+            .set("attester", &evt.attester)
+            .set("url", &evt.url)
+            .set("contract_address", Hex(&evt.contract_address).to_string())
+            .set("tx_hash", &evt.evt_tx_hash)
+            .set("block_number", evt.evt_block_number)
+            .set("attested_at", evt.evt_block_time.as_ref().unwrap());
+    });
+}
+
+/// Flattens the transaction and log context of an event into its row.
+fn set_event_context(row: &mut Row, context: &Option<contract::EventContext>) {
+    let context = context.clone().unwrap_or_default();
+    row.set("evt_block_hash", &context.block_hash)
+        .set("evt_tx_index", context.tx_index)
+        .set("evt_tx_from", Hex(&context.tx_from).to_string())
+        .set("evt_tx_to", Hex(&context.tx_to).to_string())
+        .set("evt_tx_nonce", context.tx_nonce)
+        .set("evt_tx_gas_used", context.tx_gas_used)
+        .set(
+            "evt_tx_effective_gas_price",
+            &context.tx_effective_gas_price,
+        )
+        .set("evt_ordinal", context.log_ordinal);
+}
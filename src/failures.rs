@@ -0,0 +1,111 @@
+use crate::mapping;
+use crate::params::TrackedContracts;
+use crate::pb::contract::v1 as contract;
+use ethabi::{ParamType, Token};
+use substreams::scalar::BigInt;
+use substreams::Hex;
+use substreams_ethereum::pb::eth::v2 as eth;
+
+// Selectors of the errors Solidity raises on its own: `Error(string)` for `revert("...")` and
+// `require(.., "...")`, `Panic(uint256)` for failed assertions, overflows, ...
+const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+
+/// Collects the calls to `contracts` that failed, whether the transaction itself failed or the
+/// failure was caught by a caller. `blk.transactions()` only yields successful transactions, so
+/// the traces are walked directly.
+pub fn failed_calls(blk: &eth::Block, contracts: &TrackedContracts) -> contract::FailedCalls {
+    let failed_calls = blk
+        .transaction_traces
+        .iter()
+        .flat_map(|trx| {
+            trx.calls
+                .iter()
+                .filter(|call| call.status_failed && contracts.contains(&call.address))
+                .map(move |call| failed_call(blk, trx, call))
+        })
+        .collect();
+
+    contract::FailedCalls { failed_calls }
+}
+
+fn failed_call(
+    blk: &eth::Block,
+    trx: &eth::TransactionTrace,
+    call: &eth::Call,
+) -> contract::FailedCall {
+    let (error_name, error_args) = decode_revert(&call.return_data).unwrap_or_default();
+
+    contract::FailedCall {
+        call_tx_hash: Hex(&trx.hash).to_string(),
+        call_block_time: Some(blk.timestamp().to_owned()),
+        call_block_number: blk.number,
+        call_ordinal: call.begin_ordinal,
+        call_caller: call.caller.clone(),
+        call_gas_used: call.gas_consumed,
+        tx_from: trx.from.clone(),
+        tx_failed: trx.status != eth::TransactionTraceStatus::Succeeded as i32,
+        contract_address: call.address.clone(),
+        function: mapping::function_name(&call.input)
+            .unwrap_or_default()
+            .to_string(),
+        input: call.input.clone(),
+        failure_reason: call.failure_reason.clone(),
+        revert_data: call.return_data.clone(),
+        error_name,
+        error_args,
+    }
+}
+
+/// Decodes revert data against the ABI custom errors and Solidity's built-in errors.
+fn decode_revert(data: &[u8]) -> Option<(String, Vec<contract::ErrorArgument>)> {
+    let (name, inputs) = match data.get(..4)? {
+        selector if selector == ERROR_SELECTOR => ("Error", vec![("message", ParamType::String)]),
+        selector if selector == PANIC_SELECTOR => ("Panic", vec![("code", ParamType::Uint(256))]),
+        _ => {
+            let error = mapping::custom_error(data)?;
+            (error.name, error.inputs)
+        }
+    };
+
+    let kinds = inputs
+        .iter()
+        .map(|(_, kind)| kind.clone())
+        .collect::<Vec<_>>();
+    let tokens = ethabi::decode(&kinds, &data[4..]).ok()?;
+    let args = inputs
+        .iter()
+        .zip(tokens)
+        .map(|((name, _), token)| contract::ErrorArgument {
+            name: name.to_string(),
+            value: token_value(token),
+        })
+        .collect();
+
+    Some((name.to_string(), args))
+}
+
+// Renders arguments the way the rest of the package does: hex for addresses and bytes, decimal
+// for integers.
+fn token_value(token: Token) -> String {
+    match token {
+        Token::Address(address) => Hex(address.as_bytes()).to_string(),
+        Token::FixedBytes(bytes) | Token::Bytes(bytes) => Hex(bytes).to_string(),
+        Token::Uint(value) => {
+            let mut be_bytes = [0u8; 32];
+            value.to_big_endian(&mut be_bytes);
+            BigInt::from_unsigned_bytes_be(&be_bytes).to_string()
+        }
+        Token::Int(value) => {
+            let mut be_bytes = [0u8; 32];
+            value.to_big_endian(&mut be_bytes);
+            BigInt::from_signed_bytes_be(&be_bytes).to_string()
+        }
+        Token::Bool(value) => value.to_string(),
+        Token::String(value) => value,
+        Token::FixedArray(tokens) | Token::Array(tokens) | Token::Tuple(tokens) => {
+            let values = tokens.into_iter().map(token_value).collect::<Vec<_>>();
+            format!("[{}]", values.join(","))
+        }
+    }
+}
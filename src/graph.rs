@@ -0,0 +1,133 @@
+use crate::numeric;
+use crate::pb::contract::v1 as contract;
+use crate::ZERO_ADDRESS;
+use substreams::Hex;
+use substreams_entity_change::tables::Tables as EntityChangesTables;
+
+pub fn graph_nft_out(events: &contract::Events, tables: &mut EntityChangesTables) {
+    events.nft_transfers.iter().for_each(|evt| {
+        // A burned token keeps the zero address as its owner, so that one needs an `Account`
+        // too; the zero `from` of a mint is referenced by nothing.
+        if evt.from != ZERO_ADDRESS {
+            tables.create_row("Account", Hex(&evt.from).to_string());
+        }
+        tables.create_row("Account", Hex(&evt.to).to_string());
+
+        let token_id = format!("{}-{}", Hex(&evt.contract_address), evt.token_id);
+        if evt.from == ZERO_ADDRESS {
+            tables
+                .create_row("Token", &token_id)
+                .set("contract", &evt.contract_address)
+                .set("tokenId", numeric::big_int(&evt.token_id_bigint))
+                .set("owner", Hex(&evt.to).to_string())
+                .set("burned", false)
+                .set("mintTxHash", &evt.evt_tx_hash)
+                .set("mintBlockNumber", evt.evt_block_number)
+                .set(
+                    "mintTimestamp",
+                    evt.evt_block_time.as_ref().unwrap().seconds,
+                );
+        } else {
+            tables
+                .update_row("Token", &token_id)
+                .set("owner", Hex(&evt.to).to_string())
+                .set("burned", evt.to == ZERO_ADDRESS);
+        }
+    });
+
+    // Grants and revokes are replayed in log order so that a revoke followed by a
+    // re-grant within the same block ends up active.
+    let mut role_changes = events
+        .nft_role_granteds
+        .iter()
+        .map(|evt| {
+            (
+                evt.evt_index,
+                &evt.contract_address,
+                &evt.role,
+                &evt.account,
+                &evt.sender,
+                evt.evt_block_number,
+                true,
+            )
+        })
+        .chain(events.nft_role_revokeds.iter().map(|evt| {
+            (
+                evt.evt_index,
+                &evt.contract_address,
+                &evt.role,
+                &evt.account,
+                &evt.sender,
+                evt.evt_block_number,
+                false,
+            )
+        }))
+        .collect::<Vec<_>>();
+    role_changes.sort_by_key(|(evt_index, ..)| *evt_index);
+
+    role_changes.into_iter().for_each(
+        |(_, contract, role, account, sender, block_number, active)| {
+            tables.create_row("Account", Hex(account).to_string());
+            tables
+                .update_row(
+                    "Role",
+                    format!("{}-{}-{}", Hex(contract), Hex(role), Hex(account)),
+                )
+                .set("contract", contract)
+                .set("role", role)
+                .set("account", Hex(account).to_string())
+                .set("sender", sender)
+                .set("active", active)
+                .set("updatedBlockNumber", block_number);
+        },
+    );
+}
+
+pub fn graph_attester_out(events: &contract::Events, tables: &mut EntityChangesTables) {
+    events.attester_request_sents.iter().for_each(|evt| {
+        tables
+            .create_row("OracleRequest", Hex(&evt.id).to_string())
+            .set("status", "PENDING")
+            .set("sentTxHash", &evt.evt_tx_hash)
+            .set("sentBlockNumber", evt.evt_block_number)
+            .set(
+                "sentTimestamp",
+                evt.evt_block_time.as_ref().unwrap().seconds,
+            );
+    });
+    events.attester_responses.iter().for_each(|evt| {
+        tables
+            .update_row("OracleRequest", Hex(&evt.request_id).to_string())
+            .set(
+                "status",
+                if evt.err.is_empty() {
+                    "RESPONDED"
+                } else {
+                    "ERRORED"
+                },
+            )
+            .set("response", &evt.response)
+            .set("error", &evt.err)
+            .set("responseBlockNumber", evt.evt_block_number);
+    });
+    events.attester_request_fulfilleds.iter().for_each(|evt| {
+        tables
+            .update_row("OracleRequest", Hex(&evt.id).to_string())
+            .set("fulfilledBlockNumber", evt.evt_block_number);
+    });
+    events.attester_attesteds.iter().for_each(|evt| {
+        tables.create_row("Account", Hex(&evt.owner).to_string());
+        tables
+            .create_row("Attestation", Hex(&evt.request_id).to_string())
+            .set("request", Hex(&evt.request_id).to_string())
+            .set("owner", Hex(&evt.owner).to_string())
+            .set("attester", &evt.attester)
+            .set("url", &evt.url)
+            .set("txHash", &evt.evt_tx_hash)
+            .set("blockNumber", evt.evt_block_number)
+            .set("timestamp", evt.evt_block_time.as_ref().unwrap().seconds);
+        tables
+            .update_row("OracleRequest", Hex(&evt.request_id).to_string())
+            .set("status", "FULFILLED");
+    });
+}
@@ -0,0 +1,40 @@
+use std::collections::BTreeSet;
+use substreams::pb::sf::substreams::index::v1::Keys;
+use substreams::Hex;
+use substreams_ethereum::pb::eth::v2 as eth;
+
+/// Block index keys of the logs of successful transactions, the ones `map_events` decodes:
+/// `evt_addr:0x<emitter>` and `evt_sig:0x<topic0>`.
+pub fn event_keys(blk: &eth::Block) -> Keys {
+    let mut keys = BTreeSet::new();
+    for log in blk.logs() {
+        keys.insert(format!("evt_addr:0x{}", Hex(log.address())));
+        if let Some(signature) = log.topics().first() {
+            keys.insert(format!("evt_sig:0x{}", Hex(signature)));
+        }
+    }
+
+    Keys {
+        keys: keys.into_iter().collect(),
+    }
+}
+
+/// Block index keys of every call, failed ones included since `map_failures` needs them:
+/// `call_to:0x<callee>` and `call_method:0x<selector>`.
+pub fn call_keys(blk: &eth::Block) -> Keys {
+    let mut keys = BTreeSet::new();
+    for call in blk
+        .transaction_traces
+        .iter()
+        .flat_map(|trx| trx.calls.iter())
+    {
+        keys.insert(format!("call_to:0x{}", Hex(&call.address)));
+        if let Some(selector) = call.input.get(..4) {
+            keys.insert(format!("call_method:0x{}", Hex(selector)));
+        }
+    }
+
+    Keys {
+        keys: keys.into_iter().collect(),
+    }
+}
@@ -1,330 +1,426 @@
-mod abi;
-mod pb;
-use hex_literal::hex;
+// The handler macros expand `params: String` arguments into raw pointer reads.
+#![allow(clippy::not_unsafe_ptr_arg_deref)]
+
+// Generated by build.rs on every build, so formatting them would not stick.
+#[allow(clippy::all, dead_code)]
+#[rustfmt::skip]
+pub mod abi;
+mod approvals;
+#[cfg(feature = "bench-baseline")]
+mod baseline;
+mod costs;
+mod db;
+mod failures;
+mod graph;
+mod index;
+#[allow(clippy::all, dead_code)]
+pub mod pb;
+#[allow(dead_code)]
+#[rustfmt::skip]
+mod mapping;
+mod metadata;
+pub mod modules;
+mod numeric;
+mod oracle;
+mod ownership;
+pub mod params;
+mod provenance;
+#[cfg(feature = "replay")]
+pub mod replay;
+mod roles;
+mod router;
+mod serialize;
+use params::TrackedContracts;
 use pb::contract::v1 as contract;
+use substreams::key;
+use substreams::pb::sf::substreams::index::v1::Keys;
+use substreams::pb::substreams::Clock;
+use substreams::scalar::BigInt;
+use substreams::store::{
+    Appender, DeltaBigInt, Deltas, StoreAdd, StoreAddBigInt, StoreAppend, StoreGet, StoreGetArray,
+    StoreGetProto, StoreGetString, StoreNew, StoreSet, StoreSetProto, StoreSetString,
+};
 use substreams::Hex;
+use substreams_database_change::pb::database::DatabaseChanges;
+use substreams_entity_change::pb::entity::EntityChanges;
 use substreams_ethereum::pb::eth::v2 as eth;
-use substreams_ethereum::Event;
-
-#[allow(unused_imports)]
-use num_traits::cast::ToPrimitive;
-use std::str::FromStr;
-use substreams::scalar::BigDecimal;
 
 substreams_ethereum::init!();
 
-const NFT_TRACKED_CONTRACT: [u8; 20] = hex!("4b79800e11fa527b01685056970d62878240ea46");
-const ATTESTER_TRACKED_CONTRACT: [u8; 20] = hex!("d798a4ade873e2d447b43af34e11882efed911b1");
-
-fn map_nft_events(blk: &eth::Block, events: &mut contract::Events) {
-    events.nft_approvals.append(&mut blk
-        .receipts()
-        .flat_map(|view| {
-            view.receipt.logs.iter()
-                .filter(|log| log.address == NFT_TRACKED_CONTRACT)
-                .filter_map(|log| {
-                    if let Some(event) = abi::nft_contract::events::Approval::match_and_decode(log) {
-                        return Some(contract::NftApproval {
-                            evt_tx_hash: Hex(&view.transaction.hash).to_string(),
-                            evt_index: log.block_index,
-                            evt_block_time: Some(blk.timestamp().to_owned()),
-                            evt_block_number: blk.number,
-                            approved: event.approved,
-                            owner: event.owner,
-                            token_id: event.token_id.to_string(),
-                        });
-                    }
-
-                    None
-                })
-        })
-        .collect());
-    events.nft_approval_for_alls.append(&mut blk
-        .receipts()
-        .flat_map(|view| {
-            view.receipt.logs.iter()
-                .filter(|log| log.address == NFT_TRACKED_CONTRACT)
-                .filter_map(|log| {
-                    if let Some(event) = abi::nft_contract::events::ApprovalForAll::match_and_decode(log) {
-                        return Some(contract::NftApprovalForAll {
-                            evt_tx_hash: Hex(&view.transaction.hash).to_string(),
-                            evt_index: log.block_index,
-                            evt_block_time: Some(blk.timestamp().to_owned()),
-                            evt_block_number: blk.number,
-                            approved: event.approved,
-                            operator: event.operator,
-                            owner: event.owner,
-                        });
-                    }
-
-                    None
-                })
-        })
-        .collect());
-    events.nft_batch_metadata_updates.append(&mut blk
-        .receipts()
-        .flat_map(|view| {
-            view.receipt.logs.iter()
-                .filter(|log| log.address == NFT_TRACKED_CONTRACT)
-                .filter_map(|log| {
-                    if let Some(event) = abi::nft_contract::events::BatchMetadataUpdate::match_and_decode(log) {
-                        return Some(contract::NftBatchMetadataUpdate {
-                            evt_tx_hash: Hex(&view.transaction.hash).to_string(),
-                            evt_index: log.block_index,
-                            evt_block_time: Some(blk.timestamp().to_owned()),
-                            evt_block_number: blk.number,
-                            u_from_token_id: event.u_from_token_id.to_string(),
-                            u_to_token_id: event.u_to_token_id.to_string(),
-                        });
-                    }
-
-                    None
-                })
-        })
-        .collect());
-    events.nft_metadata_updates.append(&mut blk
-        .receipts()
-        .flat_map(|view| {
-            view.receipt.logs.iter()
-                .filter(|log| log.address == NFT_TRACKED_CONTRACT)
-                .filter_map(|log| {
-                    if let Some(event) = abi::nft_contract::events::MetadataUpdate::match_and_decode(log) {
-                        return Some(contract::NftMetadataUpdate {
-                            evt_tx_hash: Hex(&view.transaction.hash).to_string(),
-                            evt_index: log.block_index,
-                            evt_block_time: Some(blk.timestamp().to_owned()),
-                            evt_block_number: blk.number,
-                            u_token_id: event.u_token_id.to_string(),
-                        });
-                    }
-
-                    None
-                })
-        })
-        .collect());
-    events.nft_role_admin_changeds.append(&mut blk
-        .receipts()
-        .flat_map(|view| {
-            view.receipt.logs.iter()
-                .filter(|log| log.address == NFT_TRACKED_CONTRACT)
-                .filter_map(|log| {
-                    if let Some(event) = abi::nft_contract::events::RoleAdminChanged::match_and_decode(log) {
-                        return Some(contract::NftRoleAdminChanged {
-                            evt_tx_hash: Hex(&view.transaction.hash).to_string(),
-                            evt_index: log.block_index,
-                            evt_block_time: Some(blk.timestamp().to_owned()),
-                            evt_block_number: blk.number,
-                            new_admin_role: Vec::from(event.new_admin_role),
-                            previous_admin_role: Vec::from(event.previous_admin_role),
-                            role: Vec::from(event.role),
-                        });
-                    }
-
-                    None
-                })
-        })
-        .collect());
-    events.nft_role_granteds.append(&mut blk
-        .receipts()
-        .flat_map(|view| {
-            view.receipt.logs.iter()
-                .filter(|log| log.address == NFT_TRACKED_CONTRACT)
-                .filter_map(|log| {
-                    if let Some(event) = abi::nft_contract::events::RoleGranted::match_and_decode(log) {
-                        return Some(contract::NftRoleGranted {
-                            evt_tx_hash: Hex(&view.transaction.hash).to_string(),
-                            evt_index: log.block_index,
-                            evt_block_time: Some(blk.timestamp().to_owned()),
-                            evt_block_number: blk.number,
-                            account: event.account,
-                            role: Vec::from(event.role),
-                            sender: event.sender,
-                        });
-                    }
-
-                    None
-                })
-        })
-        .collect());
-    events.nft_role_revokeds.append(&mut blk
-        .receipts()
-        .flat_map(|view| {
-            view.receipt.logs.iter()
-                .filter(|log| log.address == NFT_TRACKED_CONTRACT)
-                .filter_map(|log| {
-                    if let Some(event) = abi::nft_contract::events::RoleRevoked::match_and_decode(log) {
-                        return Some(contract::NftRoleRevoked {
-                            evt_tx_hash: Hex(&view.transaction.hash).to_string(),
-                            evt_index: log.block_index,
-                            evt_block_time: Some(blk.timestamp().to_owned()),
-                            evt_block_number: blk.number,
-                            account: event.account,
-                            role: Vec::from(event.role),
-                            sender: event.sender,
-                        });
-                    }
-
-                    None
-                })
-        })
-        .collect());
-    events.nft_transfers.append(&mut blk
-        .receipts()
-        .flat_map(|view| {
-            view.receipt.logs.iter()
-                .filter(|log| log.address == NFT_TRACKED_CONTRACT)
-                .filter_map(|log| {
-                    if let Some(event) = abi::nft_contract::events::Transfer::match_and_decode(log) {
-                        return Some(contract::NftTransfer {
-                            evt_tx_hash: Hex(&view.transaction.hash).to_string(),
-                            evt_index: log.block_index,
-                            evt_block_time: Some(blk.timestamp().to_owned()),
-                            evt_block_number: blk.number,
-                            from: event.from,
-                            to: event.to,
-                            token_id: event.token_id.to_string(),
-                        });
-                    }
-
-                    None
-                })
-        })
-        .collect());
-}
-fn map_attester_events(blk: &eth::Block, events: &mut contract::Events) {
-    events.attester_attesteds.append(&mut blk
-        .receipts()
-        .flat_map(|view| {
-            view.receipt.logs.iter()
-                .filter(|log| log.address == ATTESTER_TRACKED_CONTRACT)
-                .filter_map(|log| {
-                    if let Some(event) = abi::attester_contract::events::Attested::match_and_decode(log) {
-                        return Some(contract::AttesterAttested {
-                            evt_tx_hash: Hex(&view.transaction.hash).to_string(),
-                            evt_index: log.block_index,
-                            evt_block_time: Some(blk.timestamp().to_owned()),
-                            evt_block_number: blk.number,
-                            attester: event.attester,
-                            owner: event.owner,
-                            request_id: Vec::from(event.request_id),
-                            url: event.url,
-                        });
-                    }
-
-                    None
-                })
-        })
-        .collect());
-    events.attester_ownership_transfer_requesteds.append(&mut blk
-        .receipts()
-        .flat_map(|view| {
-            view.receipt.logs.iter()
-                .filter(|log| log.address == ATTESTER_TRACKED_CONTRACT)
-                .filter_map(|log| {
-                    if let Some(event) = abi::attester_contract::events::OwnershipTransferRequested::match_and_decode(log) {
-                        return Some(contract::AttesterOwnershipTransferRequested {
-                            evt_tx_hash: Hex(&view.transaction.hash).to_string(),
-                            evt_index: log.block_index,
-                            evt_block_time: Some(blk.timestamp().to_owned()),
-                            evt_block_number: blk.number,
-                            from: event.from,
-                            to: event.to,
-                        });
-                    }
-
-                    None
-                })
-        })
-        .collect());
-    events.attester_ownership_transferreds.append(&mut blk
-        .receipts()
-        .flat_map(|view| {
-            view.receipt.logs.iter()
-                .filter(|log| log.address == ATTESTER_TRACKED_CONTRACT)
-                .filter_map(|log| {
-                    if let Some(event) = abi::attester_contract::events::OwnershipTransferred::match_and_decode(log) {
-                        return Some(contract::AttesterOwnershipTransferred {
-                            evt_tx_hash: Hex(&view.transaction.hash).to_string(),
-                            evt_index: log.block_index,
-                            evt_block_time: Some(blk.timestamp().to_owned()),
-                            evt_block_number: blk.number,
-                            from: event.from,
-                            to: event.to,
-                        });
-                    }
-
-                    None
-                })
-        })
-        .collect());
-    events.attester_request_fulfilleds.append(&mut blk
-        .receipts()
-        .flat_map(|view| {
-            view.receipt.logs.iter()
-                .filter(|log| log.address == ATTESTER_TRACKED_CONTRACT)
-                .filter_map(|log| {
-                    if let Some(event) = abi::attester_contract::events::RequestFulfilled::match_and_decode(log) {
-                        return Some(contract::AttesterRequestFulfilled {
-                            evt_tx_hash: Hex(&view.transaction.hash).to_string(),
-                            evt_index: log.block_index,
-                            evt_block_time: Some(blk.timestamp().to_owned()),
-                            evt_block_number: blk.number,
-                            id: Vec::from(event.id),
-                        });
-                    }
-
-                    None
-                })
-        })
-        .collect());
-    events.attester_request_sents.append(&mut blk
-        .receipts()
-        .flat_map(|view| {
-            view.receipt.logs.iter()
-                .filter(|log| log.address == ATTESTER_TRACKED_CONTRACT)
-                .filter_map(|log| {
-                    if let Some(event) = abi::attester_contract::events::RequestSent::match_and_decode(log) {
-                        return Some(contract::AttesterRequestSent {
-                            evt_tx_hash: Hex(&view.transaction.hash).to_string(),
-                            evt_index: log.block_index,
-                            evt_block_time: Some(blk.timestamp().to_owned()),
-                            evt_block_number: blk.number,
-                            id: Vec::from(event.id),
-                        });
-                    }
-
-                    None
+const ZERO_ADDRESS: [u8; 20] = [0u8; 20];
+
+/// Walks the block's logs once, handing each log emitted by a tracked contract to the
+/// decoder matching its signature (`topics[0]`).
+pub fn map_tracked_events(blk: &eth::Block, contracts: &TrackedContracts) -> contract::Events {
+    let mut events = contract::Events::default();
+    for view in blk.receipts() {
+        for log in view
+            .receipt
+            .logs
+            .iter()
+            .filter(|log| contracts.contains(&log.address))
+        {
+            mapping::map_event(blk, view.transaction, log, &mut events);
+        }
+    }
+    oracle::decode_responses(&mut events.attester_responses);
+    events
+}
+
+#[substreams::handlers::map]
+fn index_events(blk: eth::Block) -> Result<Keys, substreams::errors::Error> {
+    modules::index_events(&blk)
+}
+
+#[substreams::handlers::map]
+fn index_calls(blk: eth::Block) -> Result<Keys, substreams::errors::Error> {
+    modules::index_calls(&blk)
+}
+
+#[substreams::handlers::map]
+fn map_events(
+    params: String,
+    blk: eth::Block,
+) -> Result<contract::Events, substreams::errors::Error> {
+    modules::map_events(&params, &blk)
+}
+
+#[substreams::handlers::map]
+fn map_calls(
+    params: String,
+    blk: eth::Block,
+) -> Result<contract::Calls, substreams::errors::Error> {
+    modules::map_calls(&params, &blk)
+}
+
+#[substreams::handlers::map]
+fn map_failures(
+    params: String,
+    blk: eth::Block,
+) -> Result<contract::FailedCalls, substreams::errors::Error> {
+    modules::map_failures(&params, &blk)
+}
+
+#[substreams::handlers::map]
+fn map_router_events(
+    params: String,
+    blk: eth::Block,
+    request_steps: StoreGetProto<contract::OracleRequestStep>,
+) -> Result<contract::Events, substreams::errors::Error> {
+    modules::map_router_events(&params, &blk, &request_steps)
+}
+
+#[substreams::handlers::map]
+fn map_mints(
+    events: contract::Events,
+    calls: contract::Calls,
+) -> Result<contract::Mints, substreams::errors::Error> {
+    modules::map_mints(&events, &calls)
+}
+
+#[substreams::handlers::store]
+fn store_attestations(events: contract::Events, store: StoreSetProto<contract::AttesterAttested>) {
+    modules::store_attestations(&events, &store);
+}
+
+#[substreams::handlers::store]
+fn store_minted_photos(mints: contract::Mints, store: StoreSetProto<contract::NftMint>) {
+    modules::store_minted_photos(&mints, &store);
+}
+
+#[substreams::handlers::map]
+fn map_provenance(
+    params: String,
+    mints: contract::Mints,
+    events: contract::Events,
+    attestations: StoreGetProto<contract::AttesterAttested>,
+    minted_photos: StoreGetProto<contract::NftMint>,
+) -> Result<contract::Provenances, substreams::errors::Error> {
+    modules::map_provenance(&params, &mints, &events, &attestations, &minted_photos)
+}
+
+#[substreams::handlers::map]
+fn map_metadata_refreshes(
+    params: String,
+    events: contract::Events,
+) -> Result<contract::MetadataRefreshes, substreams::errors::Error> {
+    modules::map_metadata_refreshes(&params, &events)
+}
+
+#[substreams::handlers::map]
+fn db_out(events: contract::Events) -> Result<DatabaseChanges, substreams::errors::Error> {
+    modules::db_out(&events)
+}
+
+#[substreams::handlers::map]
+fn graph_out(events: contract::Events) -> Result<EntityChanges, substreams::errors::Error> {
+    modules::graph_out(&events)
+}
+
+#[substreams::handlers::store]
+fn store_token_owners(events: contract::Events, store: StoreSetString) {
+    modules::store_token_owners(&events, &store);
+}
+
+#[substreams::handlers::store]
+fn store_balances(events: contract::Events, store: StoreAddBigInt) {
+    modules::store_balances(&events, &store);
+}
+
+#[substreams::handlers::store]
+fn store_token_approvals(events: contract::Events, store: StoreSetString) {
+    modules::store_token_approvals(&events, &store);
+}
+
+#[substreams::handlers::store]
+fn store_operator_approvals(events: contract::Events, store: StoreAppend<String>) {
+    modules::store_operator_approvals(&events, &store);
+}
+
+#[substreams::handlers::map]
+fn map_operator_approvals(
+    events: contract::Events,
+    operators: StoreGetArray<String>,
+) -> Result<contract::OperatorApprovals, substreams::errors::Error> {
+    modules::map_operator_approvals(&events, &operators)
+}
+
+#[substreams::handlers::store]
+fn store_oracle_request_steps(
+    events: contract::Events,
+    store: StoreSetProto<contract::OracleRequestStep>,
+) {
+    modules::store_oracle_request_steps(&events, &store);
+}
+
+#[substreams::handlers::map]
+fn map_oracle_requests(
+    events: contract::Events,
+    store: StoreGetProto<contract::OracleRequestStep>,
+) -> Result<contract::OracleRequests, substreams::errors::Error> {
+    modules::map_oracle_requests(&events, &store)
+}
+
+#[substreams::handlers::store]
+fn store_role_members(events: contract::Events, store: StoreAppend<String>) {
+    modules::store_role_members(&events, &store);
+}
+
+#[substreams::handlers::store]
+fn store_role_admins(events: contract::Events, store: StoreSetString) {
+    modules::store_role_admins(&events, &store);
+}
+
+#[substreams::handlers::map]
+fn map_role_snapshots(
+    events: contract::Events,
+    members: StoreGetArray<String>,
+    admins: StoreGetString,
+) -> Result<contract::RoleSnapshots, substreams::errors::Error> {
+    modules::map_role_snapshots(&events, &members, &admins)
+}
+
+#[substreams::handlers::store]
+fn store_transaction_costs(
+    events: contract::Events,
+    store: StoreSetProto<contract::TransactionCost>,
+) {
+    modules::store_transaction_costs(&events, &store);
+}
+
+#[substreams::handlers::map]
+fn map_attestation_costs(
+    events: contract::Events,
+    store: StoreGetProto<contract::TransactionCost>,
+) -> Result<contract::AttestationCosts, substreams::errors::Error> {
+    modules::map_attestation_costs(&events, &store)
+}
+
+#[substreams::handlers::store]
+fn store_daily_costs(events: contract::Events, store: StoreAddBigInt) {
+    modules::store_daily_costs(&events, &store);
+}
+
+#[substreams::handlers::store]
+fn store_attester_ownership(
+    events: contract::Events,
+    store: StoreSetProto<contract::AttesterOwnership>,
+) {
+    modules::store_attester_ownership(&events, &store);
+}
+
+#[substreams::handlers::store]
+fn store_transfer_requests(events: contract::Events, store: StoreAppend<String>) {
+    modules::store_transfer_requests(&events, &store);
+}
+
+#[substreams::handlers::map]
+fn map_attester_ownership(
+    events: contract::Events,
+    ownerships: StoreGetProto<contract::AttesterOwnership>,
+) -> Result<contract::AttesterOwnerships, substreams::errors::Error> {
+    modules::map_attester_ownership(&events, &ownerships)
+}
+
+#[substreams::handlers::map]
+fn map_stale_transfers(
+    params: String,
+    clock: Clock,
+    ownerships: StoreGetProto<contract::AttesterOwnership>,
+    requests: StoreGetArray<String>,
+) -> Result<contract::OwnershipAlerts, substreams::errors::Error> {
+    modules::map_stale_transfers(&params, &clock, &ownerships, &requests)
+}
+
+#[substreams::handlers::map]
+fn map_balance_changes(
+    deltas: Deltas<DeltaBigInt>,
+) -> Result<contract::BalanceChanges, substreams::errors::Error> {
+    modules::map_balance_changes(deltas)
+}
+
+/// Pairs every mint transfer with the `safeMint` call that made it, for its token uri and minter.
+fn nft_mints(events: &contract::Events, calls: &contract::Calls) -> contract::Mints {
+    let mut safe_mints = calls
+        .nft_call_safe_mints
+        .iter()
+        .filter(|call| call.call_success)
+        .collect::<Vec<_>>();
+    safe_mints.sort_by_key(|call| call.call_ordinal);
+
+    // Several mints can share a transaction (e.g. through a multicall), so each mint
+    // transfer consumes the first not yet matched `safeMint` to the same recipient on the
+    // same deployment.
+    let mints = events
+        .nft_transfers
+        .iter()
+        .filter(|transfer| transfer.from == ZERO_ADDRESS)
+        .map(|transfer| {
+            let call = safe_mints
+                .iter()
+                .position(|call| {
+                    call.call_tx_hash == transfer.evt_tx_hash
+                        && call.contract_address == transfer.contract_address
+                        && call.to == transfer.to
                 })
+                .map(|position| safe_mints.remove(position));
+
+            contract::NftMint {
+                evt_tx_hash: transfer.evt_tx_hash.clone(),
+                evt_index: transfer.evt_index,
+                evt_block_time: transfer.evt_block_time,
+                evt_block_number: transfer.evt_block_number,
+                token_id: transfer.token_id.clone(),
+                token_id_bigint: transfer.token_id_bigint.clone(),
+                to: transfer.to.clone(),
+                uri: call.map(|call| call.uri.clone()).unwrap_or_default(),
+                minter: call
+                    .map(|call| call.call_caller.clone())
+                    .unwrap_or_default(),
+                contract_address: transfer.contract_address.clone(),
+            }
         })
-        .collect());
-    events.attester_responses.append(&mut blk
-        .receipts()
-        .flat_map(|view| {
-            view.receipt.logs.iter()
-                .filter(|log| log.address == ATTESTER_TRACKED_CONTRACT)
-                .filter_map(|log| {
-                    if let Some(event) = abi::attester_contract::events::Response::match_and_decode(log) {
-                        return Some(contract::AttesterResponse {
-                            evt_tx_hash: Hex(&view.transaction.hash).to_string(),
-                            evt_index: log.block_index,
-                            evt_block_time: Some(blk.timestamp().to_owned()),
-                            evt_block_number: blk.number,
-                            err: event.err,
-                            request_id: Vec::from(event.request_id),
-                            response: event.response,
-                        });
-                    }
-
-                    None
-                })
+        .collect();
+
+    contract::Mints { mints }
+}
+
+/// Records the owner of every token, and forgets burnt tokens.
+fn set_token_owners(events: &contract::Events, store: &impl StoreSet<String>) {
+    for transfer in &events.nft_transfers {
+        let key = token_owner_key(&transfer.contract_address, &transfer.token_id_bigint);
+        if transfer.to == ZERO_ADDRESS {
+            store.delete_prefix(transfer.evt_index as i64, &key);
+        } else {
+            store.set(
+                transfer.evt_index as u64,
+                key,
+                &Hex(&transfer.to).to_string(),
+            );
+        }
+    }
+}
+
+/// Counts the tokens every account holds.
+fn add_balances(events: &contract::Events, store: &impl StoreAdd<BigInt>) {
+    for transfer in &events.nft_transfers {
+        let ordinal = transfer.evt_index as u64;
+        if transfer.from != ZERO_ADDRESS {
+            store.add(
+                ordinal,
+                account_balance_key(&transfer.contract_address, &transfer.from),
+                BigInt::from(-1),
+            );
+        }
+        if transfer.to != ZERO_ADDRESS {
+            store.add(
+                ordinal,
+                account_balance_key(&transfer.contract_address, &transfer.to),
+                BigInt::one(),
+            );
+        }
+    }
+}
+
+/// One change per balance delta, flagging the accounts left without tokens.
+fn balance_changes(
+    deltas: Deltas<DeltaBigInt>,
+) -> Result<contract::BalanceChanges, substreams::errors::Error> {
+    let balance_changes = deltas
+        .into_iter()
+        .map(|delta| {
+            Ok(contract::BalanceChange {
+                ordinal: delta.ordinal,
+                contract_address: Hex::decode(key::segment_at(&delta.key, 1))?,
+                owner: Hex::decode(key::segment_at(&delta.key, 2))?,
+                emptied: delta.new_value.is_zero() && !delta.old_value.is_zero(),
+                old_balance: delta.old_value.to_string(),
+                new_balance: delta.new_value.to_string(),
+            })
         })
-        .collect());
+        .collect::<Result<Vec<_>, substreams::errors::Error>>()?;
+
+    Ok(contract::BalanceChanges { balance_changes })
 }
-#[substreams::handlers::map]
-fn map_events(blk: eth::Block) -> Result<contract::Events, substreams::errors::Error> {
-    let mut events = contract::Events::default();
-    map_nft_events(&blk, &mut events);
-    map_attester_events(&blk, &mut events);
-    Ok(events)
+
+fn call_value(call: &eth::Call) -> String {
+    call.value
+        .as_ref()
+        .map(|value| Into::<BigInt>::into(value).to_string())
+        .unwrap_or_else(|| "0".to_string())
+}
+
+fn event_context(
+    blk: &eth::Block,
+    trx: &eth::TransactionTrace,
+    log: &eth::Log,
+) -> contract::EventContext {
+    contract::EventContext {
+        block_hash: Hex(&blk.hash).to_string(),
+        tx_index: trx.index,
+        tx_from: trx.from.clone(),
+        tx_to: trx.to.clone(),
+        tx_nonce: trx.nonce,
+        tx_gas_used: trx.gas_used,
+        tx_effective_gas_price: trx
+            .gas_price
+            .as_ref()
+            .map(|price| Into::<BigInt>::into(price).to_string())
+            .unwrap_or_else(|| "0".to_string()),
+        log_ordinal: log.ordinal,
+    }
+}
+
+// Token ids are fixed-width hex so keys sort numerically, and the trailing `:owner` keeps
+// the key prefix-unique for `delete_prefix` on burn.
+fn token_owner_key(contract_address: &[u8], token_id: &Option<contract::BigInt>) -> String {
+    format!(
+        "token:{}:{}:owner",
+        Hex(contract_address),
+        numeric::sortable_key(token_id)
+    )
 }
 
+fn account_balance_key(contract_address: &[u8], owner: &[u8]) -> String {
+    format!("account:{}:{}:balance", Hex(contract_address), Hex(owner))
+}
@@ -0,0 +1,868 @@
+// @generated by build.rs from abi/*.abi.json, do not edit.
+use crate::abi;
+use crate::params::TrackedContracts;
+use crate::pb::contract::v1 as contract;
+use hex_literal::hex;
+use substreams::Hex;
+use substreams_ethereum::pb::eth::v2 as eth;
+use substreams_ethereum::Event;
+use substreams_ethereum::Function;
+
+/// Decodes `log` with the ABI event matching its signature and appends it to `events`.
+/// Returns `false` when the log is not one of the ABI events.
+pub fn map_event(blk: &eth::Block, trx: &eth::TransactionTrace, log: &eth::Log, events: &mut contract::Events) -> bool {
+    match topic0(log) {
+        Some(ATTESTER_ATTESTED) => map_attester_attested(blk, trx, log, events),
+        Some(ATTESTER_OWNERSHIP_TRANSFER_REQUESTED) => map_attester_ownership_transfer_requested(blk, trx, log, events),
+        Some(ATTESTER_OWNERSHIP_TRANSFERRED) => map_attester_ownership_transferred(blk, trx, log, events),
+        Some(ATTESTER_REQUEST_FULFILLED) => map_attester_request_fulfilled(blk, trx, log, events),
+        Some(ATTESTER_REQUEST_SENT) => map_attester_request_sent(blk, trx, log, events),
+        Some(ATTESTER_RESPONSE) => map_attester_response(blk, trx, log, events),
+        Some(NFT_APPROVAL) => map_nft_approval(blk, trx, log, events),
+        Some(NFT_APPROVAL_FOR_ALL) => map_nft_approval_for_all(blk, trx, log, events),
+        Some(NFT_BATCH_METADATA_UPDATE) => map_nft_batch_metadata_update(blk, trx, log, events),
+        Some(NFT_METADATA_UPDATE) => map_nft_metadata_update(blk, trx, log, events),
+        Some(NFT_ROLE_ADMIN_CHANGED) => map_nft_role_admin_changed(blk, trx, log, events),
+        Some(NFT_ROLE_GRANTED) => map_nft_role_granted(blk, trx, log, events),
+        Some(NFT_ROLE_REVOKED) => map_nft_role_revoked(blk, trx, log, events),
+        Some(NFT_TRANSFER) => map_nft_transfer(blk, trx, log, events),
+        Some(ROUTER_REQUEST_NOT_PROCESSED) => map_router_request_not_processed(blk, trx, log, events),
+        Some(ROUTER_REQUEST_PROCESSED) => map_router_request_processed(blk, trx, log, events),
+        Some(ROUTER_REQUEST_START) => map_router_request_start(blk, trx, log, events),
+        Some(ROUTER_REQUEST_TIMED_OUT) => map_router_request_timed_out(blk, trx, log, events),
+        Some(ROUTER_SUBSCRIPTION_CANCELED) => map_router_subscription_canceled(blk, trx, log, events),
+        Some(ROUTER_SUBSCRIPTION_CONSUMER_ADDED) => map_router_subscription_consumer_added(blk, trx, log, events),
+        Some(ROUTER_SUBSCRIPTION_CONSUMER_REMOVED) => map_router_subscription_consumer_removed(blk, trx, log, events),
+        Some(ROUTER_SUBSCRIPTION_CREATED) => map_router_subscription_created(blk, trx, log, events),
+        Some(ROUTER_SUBSCRIPTION_FUNDED) => map_router_subscription_funded(blk, trx, log, events),
+        Some(ROUTER_SUBSCRIPTION_OWNER_TRANSFER_REQUESTED) => map_router_subscription_owner_transfer_requested(blk, trx, log, events),
+        Some(ROUTER_SUBSCRIPTION_OWNER_TRANSFERRED) => map_router_subscription_owner_transferred(blk, trx, log, events),
+        _ => false,
+    }
+}
+
+fn topic0(log: &eth::Log) -> Option<[u8; 32]> {
+    log.topics.first()?.as_slice().try_into().ok()
+}
+
+/// Decodes the calls made to `contracts` with the ABI functions and appends them to `calls`.
+pub fn map_calls(blk: &eth::Block, contracts: &TrackedContracts, calls: &mut contract::Calls) {
+    for tx in blk.transactions() {
+        for call in tx.calls.iter().filter(|call| contracts.contains(&call.address)) {
+            map_attester_call(blk, tx, call, calls);
+            map_nft_call(blk, tx, call, calls);
+        }
+    }
+}
+
+const ATTESTER_ATTESTED: [u8; 32] = hex!("1d1c0b956e0a05782d8ceea1b8cb94cee5c3043d8aa2a0bee82d312db21ef7c8");
+const ATTESTER_OWNERSHIP_TRANSFER_REQUESTED: [u8; 32] = hex!("ed8889f560326eb138920d842192f0eb3dd22b4f139c87a2c57538e05bae1278");
+const ATTESTER_OWNERSHIP_TRANSFERRED: [u8; 32] = hex!("8be0079c531659141344cd1fd0a4f28419497f9722a3daafe3b4186f6b6457e0");
+const ATTESTER_REQUEST_FULFILLED: [u8; 32] = hex!("85e1543bf2f84fe80c6badbce3648c8539ad1df4d2b3d822938ca0538be727e6");
+const ATTESTER_REQUEST_SENT: [u8; 32] = hex!("1131472297a800fee664d1d89cfa8f7676ff07189ecc53f80bbb5f4969099db8");
+const ATTESTER_RESPONSE: [u8; 32] = hex!("7873807bf6ddc50401cd3d29bbe0decee23fd4d68d273f4b5eb83cded4d2f172");
+
+/// Decodes `log` with the attester ABI event matching its signature and appends it to `events`.
+/// Returns `false` when the log is not one of its events.
+pub fn map_attester_event(blk: &eth::Block, trx: &eth::TransactionTrace, log: &eth::Log, events: &mut contract::Events) -> bool {
+    match topic0(log) {
+        Some(ATTESTER_ATTESTED) => map_attester_attested(blk, trx, log, events),
+        Some(ATTESTER_OWNERSHIP_TRANSFER_REQUESTED) => map_attester_ownership_transfer_requested(blk, trx, log, events),
+        Some(ATTESTER_OWNERSHIP_TRANSFERRED) => map_attester_ownership_transferred(blk, trx, log, events),
+        Some(ATTESTER_REQUEST_FULFILLED) => map_attester_request_fulfilled(blk, trx, log, events),
+        Some(ATTESTER_REQUEST_SENT) => map_attester_request_sent(blk, trx, log, events),
+        Some(ATTESTER_RESPONSE) => map_attester_response(blk, trx, log, events),
+        _ => false,
+    }
+}
+
+fn map_attester_attested(blk: &eth::Block, trx: &eth::TransactionTrace, log: &eth::Log, events: &mut contract::Events) -> bool {
+    let Some(event) = abi::attester_contract::events::Attested::match_and_decode(log) else { return false; };
+    events.attester_attesteds.push(contract::AttesterAttested {
+        evt_tx_hash: Hex(&trx.hash).to_string(),
+        evt_index: log.block_index,
+        evt_block_time: Some(blk.timestamp().to_owned()),
+        evt_block_number: blk.number,
+        contract_address: log.address.clone(),
+        evt_context: Some(crate::event_context(blk, trx, log)),
+        request_id: Vec::from(event.request_id),
+        owner: event.owner,
+        attester: event.attester,
+        url: event.url,
+    });
+    true
+}
+
+fn map_attester_ownership_transfer_requested(blk: &eth::Block, trx: &eth::TransactionTrace, log: &eth::Log, events: &mut contract::Events) -> bool {
+    let Some(event) = abi::attester_contract::events::OwnershipTransferRequested::match_and_decode(log) else { return false; };
+    events.attester_ownership_transfer_requesteds.push(contract::AttesterOwnershipTransferRequested {
+        evt_tx_hash: Hex(&trx.hash).to_string(),
+        evt_index: log.block_index,
+        evt_block_time: Some(blk.timestamp().to_owned()),
+        evt_block_number: blk.number,
+        contract_address: log.address.clone(),
+        evt_context: Some(crate::event_context(blk, trx, log)),
+        from: event.from,
+        to: event.to,
+    });
+    true
+}
+
+fn map_attester_ownership_transferred(blk: &eth::Block, trx: &eth::TransactionTrace, log: &eth::Log, events: &mut contract::Events) -> bool {
+    let Some(event) = abi::attester_contract::events::OwnershipTransferred::match_and_decode(log) else { return false; };
+    events.attester_ownership_transferreds.push(contract::AttesterOwnershipTransferred {
+        evt_tx_hash: Hex(&trx.hash).to_string(),
+        evt_index: log.block_index,
+        evt_block_time: Some(blk.timestamp().to_owned()),
+        evt_block_number: blk.number,
+        contract_address: log.address.clone(),
+        evt_context: Some(crate::event_context(blk, trx, log)),
+        from: event.from,
+        to: event.to,
+    });
+    true
+}
+
+fn map_attester_request_fulfilled(blk: &eth::Block, trx: &eth::TransactionTrace, log: &eth::Log, events: &mut contract::Events) -> bool {
+    let Some(event) = abi::attester_contract::events::RequestFulfilled::match_and_decode(log) else { return false; };
+    events.attester_request_fulfilleds.push(contract::AttesterRequestFulfilled {
+        evt_tx_hash: Hex(&trx.hash).to_string(),
+        evt_index: log.block_index,
+        evt_block_time: Some(blk.timestamp().to_owned()),
+        evt_block_number: blk.number,
+        contract_address: log.address.clone(),
+        evt_context: Some(crate::event_context(blk, trx, log)),
+        id: Vec::from(event.id),
+    });
+    true
+}
+
+fn map_attester_request_sent(blk: &eth::Block, trx: &eth::TransactionTrace, log: &eth::Log, events: &mut contract::Events) -> bool {
+    let Some(event) = abi::attester_contract::events::RequestSent::match_and_decode(log) else { return false; };
+    events.attester_request_sents.push(contract::AttesterRequestSent {
+        evt_tx_hash: Hex(&trx.hash).to_string(),
+        evt_index: log.block_index,
+        evt_block_time: Some(blk.timestamp().to_owned()),
+        evt_block_number: blk.number,
+        contract_address: log.address.clone(),
+        evt_context: Some(crate::event_context(blk, trx, log)),
+        id: Vec::from(event.id),
+    });
+    true
+}
+
+fn map_attester_response(blk: &eth::Block, trx: &eth::TransactionTrace, log: &eth::Log, events: &mut contract::Events) -> bool {
+    let Some(event) = abi::attester_contract::events::Response::match_and_decode(log) else { return false; };
+    events.attester_responses.push(contract::AttesterResponse {
+        evt_tx_hash: Hex(&trx.hash).to_string(),
+        evt_index: log.block_index,
+        evt_block_time: Some(blk.timestamp().to_owned()),
+        evt_block_number: blk.number,
+        contract_address: log.address.clone(),
+        evt_context: Some(crate::event_context(blk, trx, log)),
+        request_id: Vec::from(event.request_id),
+        response: event.response,
+        err: event.err,
+        ..Default::default()
+    });
+    true
+}
+
+const NFT_APPROVAL: [u8; 32] = hex!("8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925");
+const NFT_APPROVAL_FOR_ALL: [u8; 32] = hex!("17307eab39ab6107e8899845ad3d59bd9653f200f220920489ca2b5937696c31");
+const NFT_BATCH_METADATA_UPDATE: [u8; 32] = hex!("6bd5c950a8d8df17f772f5af37cb3655737899cbf903264b9795592da439661c");
+const NFT_METADATA_UPDATE: [u8; 32] = hex!("f8e1a15aba9398e019f0b49df1a4fde98ee17ae345cb5f6b5e2c27f5033e8ce7");
+const NFT_ROLE_ADMIN_CHANGED: [u8; 32] = hex!("bd79b86ffe0ab8e8776151514217cd7cacd52c909f66475c3af44e129f0b00ff");
+const NFT_ROLE_GRANTED: [u8; 32] = hex!("2f8788117e7eff1d82e926ec794901d17c78024a50270940304540a733656f0d");
+const NFT_ROLE_REVOKED: [u8; 32] = hex!("f6391f5c32d9c69d2a47ea670b442974b53935d1edc7fd64eb21e047a839171b");
+const NFT_TRANSFER: [u8; 32] = hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+
+/// Decodes `log` with the nft ABI event matching its signature and appends it to `events`.
+/// Returns `false` when the log is not one of its events.
+pub fn map_nft_event(blk: &eth::Block, trx: &eth::TransactionTrace, log: &eth::Log, events: &mut contract::Events) -> bool {
+    match topic0(log) {
+        Some(NFT_APPROVAL) => map_nft_approval(blk, trx, log, events),
+        Some(NFT_APPROVAL_FOR_ALL) => map_nft_approval_for_all(blk, trx, log, events),
+        Some(NFT_BATCH_METADATA_UPDATE) => map_nft_batch_metadata_update(blk, trx, log, events),
+        Some(NFT_METADATA_UPDATE) => map_nft_metadata_update(blk, trx, log, events),
+        Some(NFT_ROLE_ADMIN_CHANGED) => map_nft_role_admin_changed(blk, trx, log, events),
+        Some(NFT_ROLE_GRANTED) => map_nft_role_granted(blk, trx, log, events),
+        Some(NFT_ROLE_REVOKED) => map_nft_role_revoked(blk, trx, log, events),
+        Some(NFT_TRANSFER) => map_nft_transfer(blk, trx, log, events),
+        _ => false,
+    }
+}
+
+fn map_nft_approval(blk: &eth::Block, trx: &eth::TransactionTrace, log: &eth::Log, events: &mut contract::Events) -> bool {
+    let Some(event) = abi::nft_contract::events::Approval::match_and_decode(log) else { return false; };
+    events.nft_approvals.push(contract::NftApproval {
+        evt_tx_hash: Hex(&trx.hash).to_string(),
+        evt_index: log.block_index,
+        evt_block_time: Some(blk.timestamp().to_owned()),
+        evt_block_number: blk.number,
+        contract_address: log.address.clone(),
+        evt_context: Some(crate::event_context(blk, trx, log)),
+        owner: event.owner,
+        approved: event.approved,
+        token_id: event.token_id.to_string(),
+        token_id_bigint: Some((&event.token_id).into()),
+    });
+    true
+}
+
+fn map_nft_approval_for_all(blk: &eth::Block, trx: &eth::TransactionTrace, log: &eth::Log, events: &mut contract::Events) -> bool {
+    let Some(event) = abi::nft_contract::events::ApprovalForAll::match_and_decode(log) else { return false; };
+    events.nft_approval_for_alls.push(contract::NftApprovalForAll {
+        evt_tx_hash: Hex(&trx.hash).to_string(),
+        evt_index: log.block_index,
+        evt_block_time: Some(blk.timestamp().to_owned()),
+        evt_block_number: blk.number,
+        contract_address: log.address.clone(),
+        evt_context: Some(crate::event_context(blk, trx, log)),
+        owner: event.owner,
+        operator: event.operator,
+        approved: event.approved,
+    });
+    true
+}
+
+fn map_nft_batch_metadata_update(blk: &eth::Block, trx: &eth::TransactionTrace, log: &eth::Log, events: &mut contract::Events) -> bool {
+    let Some(event) = abi::nft_contract::events::BatchMetadataUpdate::match_and_decode(log) else { return false; };
+    events.nft_batch_metadata_updates.push(contract::NftBatchMetadataUpdate {
+        evt_tx_hash: Hex(&trx.hash).to_string(),
+        evt_index: log.block_index,
+        evt_block_time: Some(blk.timestamp().to_owned()),
+        evt_block_number: blk.number,
+        contract_address: log.address.clone(),
+        evt_context: Some(crate::event_context(blk, trx, log)),
+        u_from_token_id: event.u_from_token_id.to_string(),
+        u_to_token_id: event.u_to_token_id.to_string(),
+        u_from_token_id_bigint: Some((&event.u_from_token_id).into()),
+        u_to_token_id_bigint: Some((&event.u_to_token_id).into()),
+    });
+    true
+}
+
+fn map_nft_metadata_update(blk: &eth::Block, trx: &eth::TransactionTrace, log: &eth::Log, events: &mut contract::Events) -> bool {
+    let Some(event) = abi::nft_contract::events::MetadataUpdate::match_and_decode(log) else { return false; };
+    events.nft_metadata_updates.push(contract::NftMetadataUpdate {
+        evt_tx_hash: Hex(&trx.hash).to_string(),
+        evt_index: log.block_index,
+        evt_block_time: Some(blk.timestamp().to_owned()),
+        evt_block_number: blk.number,
+        contract_address: log.address.clone(),
+        evt_context: Some(crate::event_context(blk, trx, log)),
+        u_token_id: event.u_token_id.to_string(),
+        u_token_id_bigint: Some((&event.u_token_id).into()),
+    });
+    true
+}
+
+fn map_nft_role_admin_changed(blk: &eth::Block, trx: &eth::TransactionTrace, log: &eth::Log, events: &mut contract::Events) -> bool {
+    let Some(event) = abi::nft_contract::events::RoleAdminChanged::match_and_decode(log) else { return false; };
+    events.nft_role_admin_changeds.push(contract::NftRoleAdminChanged {
+        evt_tx_hash: Hex(&trx.hash).to_string(),
+        evt_index: log.block_index,
+        evt_block_time: Some(blk.timestamp().to_owned()),
+        evt_block_number: blk.number,
+        contract_address: log.address.clone(),
+        evt_context: Some(crate::event_context(blk, trx, log)),
+        role: Vec::from(event.role),
+        previous_admin_role: Vec::from(event.previous_admin_role),
+        new_admin_role: Vec::from(event.new_admin_role),
+    });
+    true
+}
+
+fn map_nft_role_granted(blk: &eth::Block, trx: &eth::TransactionTrace, log: &eth::Log, events: &mut contract::Events) -> bool {
+    let Some(event) = abi::nft_contract::events::RoleGranted::match_and_decode(log) else { return false; };
+    events.nft_role_granteds.push(contract::NftRoleGranted {
+        evt_tx_hash: Hex(&trx.hash).to_string(),
+        evt_index: log.block_index,
+        evt_block_time: Some(blk.timestamp().to_owned()),
+        evt_block_number: blk.number,
+        contract_address: log.address.clone(),
+        evt_context: Some(crate::event_context(blk, trx, log)),
+        role: Vec::from(event.role),
+        account: event.account,
+        sender: event.sender,
+    });
+    true
+}
+
+fn map_nft_role_revoked(blk: &eth::Block, trx: &eth::TransactionTrace, log: &eth::Log, events: &mut contract::Events) -> bool {
+    let Some(event) = abi::nft_contract::events::RoleRevoked::match_and_decode(log) else { return false; };
+    events.nft_role_revokeds.push(contract::NftRoleRevoked {
+        evt_tx_hash: Hex(&trx.hash).to_string(),
+        evt_index: log.block_index,
+        evt_block_time: Some(blk.timestamp().to_owned()),
+        evt_block_number: blk.number,
+        contract_address: log.address.clone(),
+        evt_context: Some(crate::event_context(blk, trx, log)),
+        role: Vec::from(event.role),
+        account: event.account,
+        sender: event.sender,
+    });
+    true
+}
+
+fn map_nft_transfer(blk: &eth::Block, trx: &eth::TransactionTrace, log: &eth::Log, events: &mut contract::Events) -> bool {
+    let Some(event) = abi::nft_contract::events::Transfer::match_and_decode(log) else { return false; };
+    events.nft_transfers.push(contract::NftTransfer {
+        evt_tx_hash: Hex(&trx.hash).to_string(),
+        evt_index: log.block_index,
+        evt_block_time: Some(blk.timestamp().to_owned()),
+        evt_block_number: blk.number,
+        contract_address: log.address.clone(),
+        evt_context: Some(crate::event_context(blk, trx, log)),
+        from: event.from,
+        to: event.to,
+        token_id: event.token_id.to_string(),
+        token_id_bigint: Some((&event.token_id).into()),
+    });
+    true
+}
+
+const ROUTER_REQUEST_NOT_PROCESSED: [u8; 32] = hex!("1a90e9a50793db2e394cf581e7c522e10c358a81e70acf6b5a0edd620c08dee1");
+const ROUTER_REQUEST_PROCESSED: [u8; 32] = hex!("64778f26c70b60a8d7e29e2451b3844302d959448401c0535b768ed88c6b505e");
+const ROUTER_REQUEST_START: [u8; 32] = hex!("f67aec45c9a7ede407974a3e0c3a743dffeab99ee3f2d4c9a8144c2ebf2c7ec9");
+const ROUTER_REQUEST_TIMED_OUT: [u8; 32] = hex!("f1ca1e9147be737b04a2b018a79405f687a97de8dd8a2559bbe62357343af414");
+const ROUTER_SUBSCRIPTION_CANCELED: [u8; 32] = hex!("e8ed5b475a5b5987aa9165e8731bb78043f39eee32ec5a1169a89e27fcd49815");
+const ROUTER_SUBSCRIPTION_CONSUMER_ADDED: [u8; 32] = hex!("43dc749a04ac8fb825cbd514f7c0e13f13bc6f2ee66043b76629d51776cff8e0");
+const ROUTER_SUBSCRIPTION_CONSUMER_REMOVED: [u8; 32] = hex!("182bff9831466789164ca77075fffd84916d35a8180ba73c27e45634549b445b");
+const ROUTER_SUBSCRIPTION_CREATED: [u8; 32] = hex!("464722b4166576d3dcbba877b999bc35cf911f4eaf434b7eba68fa113951d0bf");
+const ROUTER_SUBSCRIPTION_FUNDED: [u8; 32] = hex!("d39ec07f4e209f627a4c427971473820dc129761ba28de8906bd56f57101d4f8");
+const ROUTER_SUBSCRIPTION_OWNER_TRANSFER_REQUESTED: [u8; 32] = hex!("69436ea6df009049404f564eff6622cd00522b0bd6a89efd9e52a355c4a879be");
+const ROUTER_SUBSCRIPTION_OWNER_TRANSFERRED: [u8; 32] = hex!("6f1dc65165ffffedfd8e507b4a0f1fcfdada045ed11f6c26ba27cedfe87802f0");
+
+/// Decodes `log` with the router ABI event matching its signature and appends it to `events`.
+/// Returns `false` when the log is not one of its events.
+pub fn map_router_event(blk: &eth::Block, trx: &eth::TransactionTrace, log: &eth::Log, events: &mut contract::Events) -> bool {
+    match topic0(log) {
+        Some(ROUTER_REQUEST_NOT_PROCESSED) => map_router_request_not_processed(blk, trx, log, events),
+        Some(ROUTER_REQUEST_PROCESSED) => map_router_request_processed(blk, trx, log, events),
+        Some(ROUTER_REQUEST_START) => map_router_request_start(blk, trx, log, events),
+        Some(ROUTER_REQUEST_TIMED_OUT) => map_router_request_timed_out(blk, trx, log, events),
+        Some(ROUTER_SUBSCRIPTION_CANCELED) => map_router_subscription_canceled(blk, trx, log, events),
+        Some(ROUTER_SUBSCRIPTION_CONSUMER_ADDED) => map_router_subscription_consumer_added(blk, trx, log, events),
+        Some(ROUTER_SUBSCRIPTION_CONSUMER_REMOVED) => map_router_subscription_consumer_removed(blk, trx, log, events),
+        Some(ROUTER_SUBSCRIPTION_CREATED) => map_router_subscription_created(blk, trx, log, events),
+        Some(ROUTER_SUBSCRIPTION_FUNDED) => map_router_subscription_funded(blk, trx, log, events),
+        Some(ROUTER_SUBSCRIPTION_OWNER_TRANSFER_REQUESTED) => map_router_subscription_owner_transfer_requested(blk, trx, log, events),
+        Some(ROUTER_SUBSCRIPTION_OWNER_TRANSFERRED) => map_router_subscription_owner_transferred(blk, trx, log, events),
+        _ => false,
+    }
+}
+
+fn map_router_request_not_processed(blk: &eth::Block, trx: &eth::TransactionTrace, log: &eth::Log, events: &mut contract::Events) -> bool {
+    let Some(event) = abi::router_contract::events::RequestNotProcessed::match_and_decode(log) else { return false; };
+    let Some(result_code) = crate::numeric::checked_u64(&event.result_code) else { substreams::log::info!("skipping router RequestNotProcessed at tx {} log {}: result_code overflows 64 bits", Hex(&trx.hash), log.block_index); return false; };
+    events.router_request_not_processeds.push(contract::RouterRequestNotProcessed {
+        evt_tx_hash: Hex(&trx.hash).to_string(),
+        evt_index: log.block_index,
+        evt_block_time: Some(blk.timestamp().to_owned()),
+        evt_block_number: blk.number,
+        contract_address: log.address.clone(),
+        evt_context: Some(crate::event_context(blk, trx, log)),
+        request_id: Vec::from(event.request_id),
+        coordinator: event.coordinator,
+        transmitter: event.transmitter,
+        result_code,
+    });
+    true
+}
+
+fn map_router_request_processed(blk: &eth::Block, trx: &eth::TransactionTrace, log: &eth::Log, events: &mut contract::Events) -> bool {
+    let Some(event) = abi::router_contract::events::RequestProcessed::match_and_decode(log) else { return false; };
+    let Some(subscription_id) = crate::numeric::checked_u64(&event.subscription_id) else { substreams::log::info!("skipping router RequestProcessed at tx {} log {}: subscription_id overflows 64 bits", Hex(&trx.hash), log.block_index); return false; };
+    let Some(result_code) = crate::numeric::checked_u64(&event.result_code) else { substreams::log::info!("skipping router RequestProcessed at tx {} log {}: result_code overflows 64 bits", Hex(&trx.hash), log.block_index); return false; };
+    events.router_request_processeds.push(contract::RouterRequestProcessed {
+        evt_tx_hash: Hex(&trx.hash).to_string(),
+        evt_index: log.block_index,
+        evt_block_time: Some(blk.timestamp().to_owned()),
+        evt_block_number: blk.number,
+        contract_address: log.address.clone(),
+        evt_context: Some(crate::event_context(blk, trx, log)),
+        request_id: Vec::from(event.request_id),
+        subscription_id,
+        total_cost_juels: event.total_cost_juels.to_string(),
+        transmitter: event.transmitter,
+        result_code,
+        response: event.response,
+        err: event.err,
+        callback_return_data: event.callback_return_data,
+        total_cost_juels_bigint: Some((&event.total_cost_juels).into()),
+    });
+    true
+}
+
+fn map_router_request_start(blk: &eth::Block, trx: &eth::TransactionTrace, log: &eth::Log, events: &mut contract::Events) -> bool {
+    let Some(event) = abi::router_contract::events::RequestStart::match_and_decode(log) else { return false; };
+    let Some(subscription_id) = crate::numeric::checked_u64(&event.subscription_id) else { substreams::log::info!("skipping router RequestStart at tx {} log {}: subscription_id overflows 64 bits", Hex(&trx.hash), log.block_index); return false; };
+    let Some(data_version) = crate::numeric::checked_u64(&event.data_version) else { substreams::log::info!("skipping router RequestStart at tx {} log {}: data_version overflows 64 bits", Hex(&trx.hash), log.block_index); return false; };
+    let Some(callback_gas_limit) = crate::numeric::checked_u64(&event.callback_gas_limit) else { substreams::log::info!("skipping router RequestStart at tx {} log {}: callback_gas_limit overflows 64 bits", Hex(&trx.hash), log.block_index); return false; };
+    events.router_request_starts.push(contract::RouterRequestStart {
+        evt_tx_hash: Hex(&trx.hash).to_string(),
+        evt_index: log.block_index,
+        evt_block_time: Some(blk.timestamp().to_owned()),
+        evt_block_number: blk.number,
+        contract_address: log.address.clone(),
+        evt_context: Some(crate::event_context(blk, trx, log)),
+        request_id: Vec::from(event.request_id),
+        don_id: Vec::from(event.don_id),
+        subscription_id,
+        subscription_owner: event.subscription_owner,
+        requesting_contract: event.requesting_contract,
+        request_initiator: event.request_initiator,
+        data: event.data,
+        data_version,
+        callback_gas_limit,
+        estimated_total_cost_juels: event.estimated_total_cost_juels.to_string(),
+        estimated_total_cost_juels_bigint: Some((&event.estimated_total_cost_juels).into()),
+    });
+    true
+}
+
+fn map_router_request_timed_out(blk: &eth::Block, trx: &eth::TransactionTrace, log: &eth::Log, events: &mut contract::Events) -> bool {
+    let Some(event) = abi::router_contract::events::RequestTimedOut::match_and_decode(log) else { return false; };
+    events.router_request_timed_outs.push(contract::RouterRequestTimedOut {
+        evt_tx_hash: Hex(&trx.hash).to_string(),
+        evt_index: log.block_index,
+        evt_block_time: Some(blk.timestamp().to_owned()),
+        evt_block_number: blk.number,
+        contract_address: log.address.clone(),
+        evt_context: Some(crate::event_context(blk, trx, log)),
+        request_id: Vec::from(event.request_id),
+    });
+    true
+}
+
+fn map_router_subscription_canceled(blk: &eth::Block, trx: &eth::TransactionTrace, log: &eth::Log, events: &mut contract::Events) -> bool {
+    let Some(event) = abi::router_contract::events::SubscriptionCanceled::match_and_decode(log) else { return false; };
+    let Some(subscription_id) = crate::numeric::checked_u64(&event.subscription_id) else { substreams::log::info!("skipping router SubscriptionCanceled at tx {} log {}: subscription_id overflows 64 bits", Hex(&trx.hash), log.block_index); return false; };
+    events.router_subscription_canceleds.push(contract::RouterSubscriptionCanceled {
+        evt_tx_hash: Hex(&trx.hash).to_string(),
+        evt_index: log.block_index,
+        evt_block_time: Some(blk.timestamp().to_owned()),
+        evt_block_number: blk.number,
+        contract_address: log.address.clone(),
+        evt_context: Some(crate::event_context(blk, trx, log)),
+        subscription_id,
+        funds_recipient: event.funds_recipient,
+        funds_amount: event.funds_amount.to_string(),
+        funds_amount_bigint: Some((&event.funds_amount).into()),
+    });
+    true
+}
+
+fn map_router_subscription_consumer_added(blk: &eth::Block, trx: &eth::TransactionTrace, log: &eth::Log, events: &mut contract::Events) -> bool {
+    let Some(event) = abi::router_contract::events::SubscriptionConsumerAdded::match_and_decode(log) else { return false; };
+    let Some(subscription_id) = crate::numeric::checked_u64(&event.subscription_id) else { substreams::log::info!("skipping router SubscriptionConsumerAdded at tx {} log {}: subscription_id overflows 64 bits", Hex(&trx.hash), log.block_index); return false; };
+    events.router_subscription_consumer_addeds.push(contract::RouterSubscriptionConsumerAdded {
+        evt_tx_hash: Hex(&trx.hash).to_string(),
+        evt_index: log.block_index,
+        evt_block_time: Some(blk.timestamp().to_owned()),
+        evt_block_number: blk.number,
+        contract_address: log.address.clone(),
+        evt_context: Some(crate::event_context(blk, trx, log)),
+        subscription_id,
+        consumer: event.consumer,
+    });
+    true
+}
+
+fn map_router_subscription_consumer_removed(blk: &eth::Block, trx: &eth::TransactionTrace, log: &eth::Log, events: &mut contract::Events) -> bool {
+    let Some(event) = abi::router_contract::events::SubscriptionConsumerRemoved::match_and_decode(log) else { return false; };
+    let Some(subscription_id) = crate::numeric::checked_u64(&event.subscription_id) else { substreams::log::info!("skipping router SubscriptionConsumerRemoved at tx {} log {}: subscription_id overflows 64 bits", Hex(&trx.hash), log.block_index); return false; };
+    events.router_subscription_consumer_removeds.push(contract::RouterSubscriptionConsumerRemoved {
+        evt_tx_hash: Hex(&trx.hash).to_string(),
+        evt_index: log.block_index,
+        evt_block_time: Some(blk.timestamp().to_owned()),
+        evt_block_number: blk.number,
+        contract_address: log.address.clone(),
+        evt_context: Some(crate::event_context(blk, trx, log)),
+        subscription_id,
+        consumer: event.consumer,
+    });
+    true
+}
+
+fn map_router_subscription_created(blk: &eth::Block, trx: &eth::TransactionTrace, log: &eth::Log, events: &mut contract::Events) -> bool {
+    let Some(event) = abi::router_contract::events::SubscriptionCreated::match_and_decode(log) else { return false; };
+    let Some(subscription_id) = crate::numeric::checked_u64(&event.subscription_id) else { substreams::log::info!("skipping router SubscriptionCreated at tx {} log {}: subscription_id overflows 64 bits", Hex(&trx.hash), log.block_index); return false; };
+    events.router_subscription_createds.push(contract::RouterSubscriptionCreated {
+        evt_tx_hash: Hex(&trx.hash).to_string(),
+        evt_index: log.block_index,
+        evt_block_time: Some(blk.timestamp().to_owned()),
+        evt_block_number: blk.number,
+        contract_address: log.address.clone(),
+        evt_context: Some(crate::event_context(blk, trx, log)),
+        subscription_id,
+        owner: event.owner,
+    });
+    true
+}
+
+fn map_router_subscription_funded(blk: &eth::Block, trx: &eth::TransactionTrace, log: &eth::Log, events: &mut contract::Events) -> bool {
+    let Some(event) = abi::router_contract::events::SubscriptionFunded::match_and_decode(log) else { return false; };
+    let Some(subscription_id) = crate::numeric::checked_u64(&event.subscription_id) else { substreams::log::info!("skipping router SubscriptionFunded at tx {} log {}: subscription_id overflows 64 bits", Hex(&trx.hash), log.block_index); return false; };
+    events.router_subscription_fundeds.push(contract::RouterSubscriptionFunded {
+        evt_tx_hash: Hex(&trx.hash).to_string(),
+        evt_index: log.block_index,
+        evt_block_time: Some(blk.timestamp().to_owned()),
+        evt_block_number: blk.number,
+        contract_address: log.address.clone(),
+        evt_context: Some(crate::event_context(blk, trx, log)),
+        subscription_id,
+        old_balance: event.old_balance.to_string(),
+        new_balance: event.new_balance.to_string(),
+        old_balance_bigint: Some((&event.old_balance).into()),
+        new_balance_bigint: Some((&event.new_balance).into()),
+    });
+    true
+}
+
+fn map_router_subscription_owner_transfer_requested(blk: &eth::Block, trx: &eth::TransactionTrace, log: &eth::Log, events: &mut contract::Events) -> bool {
+    let Some(event) = abi::router_contract::events::SubscriptionOwnerTransferRequested::match_and_decode(log) else { return false; };
+    let Some(subscription_id) = crate::numeric::checked_u64(&event.subscription_id) else { substreams::log::info!("skipping router SubscriptionOwnerTransferRequested at tx {} log {}: subscription_id overflows 64 bits", Hex(&trx.hash), log.block_index); return false; };
+    events.router_subscription_owner_transfer_requesteds.push(contract::RouterSubscriptionOwnerTransferRequested {
+        evt_tx_hash: Hex(&trx.hash).to_string(),
+        evt_index: log.block_index,
+        evt_block_time: Some(blk.timestamp().to_owned()),
+        evt_block_number: blk.number,
+        contract_address: log.address.clone(),
+        evt_context: Some(crate::event_context(blk, trx, log)),
+        subscription_id,
+        from: event.from,
+        to: event.to,
+    });
+    true
+}
+
+fn map_router_subscription_owner_transferred(blk: &eth::Block, trx: &eth::TransactionTrace, log: &eth::Log, events: &mut contract::Events) -> bool {
+    let Some(event) = abi::router_contract::events::SubscriptionOwnerTransferred::match_and_decode(log) else { return false; };
+    let Some(subscription_id) = crate::numeric::checked_u64(&event.subscription_id) else { substreams::log::info!("skipping router SubscriptionOwnerTransferred at tx {} log {}: subscription_id overflows 64 bits", Hex(&trx.hash), log.block_index); return false; };
+    events.router_subscription_owner_transferreds.push(contract::RouterSubscriptionOwnerTransferred {
+        evt_tx_hash: Hex(&trx.hash).to_string(),
+        evt_index: log.block_index,
+        evt_block_time: Some(blk.timestamp().to_owned()),
+        evt_block_number: blk.number,
+        contract_address: log.address.clone(),
+        evt_context: Some(crate::event_context(blk, trx, log)),
+        subscription_id,
+        from: event.from,
+        to: event.to,
+    });
+    true
+}
+
+// Decodes `call` with the attester ABI function matching its selector and appends it to `calls`.
+fn map_attester_call(blk: &eth::Block, tx: &eth::TransactionTrace, call: &eth::Call, calls: &mut contract::Calls) {
+    if abi::attester_contract::functions::AcceptOwnership::match_and_decode(call).is_some() {
+        calls.attester_call_accept_ownerships.push(contract::AttesterAcceptOwnershipCall {
+            call_tx_hash: Hex(&tx.hash).to_string(),
+            call_block_time: Some(blk.timestamp().to_owned()),
+            call_block_number: blk.number,
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_caller: call.caller.clone(),
+            call_value: crate::call_value(call),
+            call_gas_used: call.gas_consumed,
+            contract_address: call.address.clone(),
+        });
+    } else if let Some(decoded_call) = abi::attester_contract::functions::HandleOracleFulfillment::match_and_decode(call) {
+        calls.attester_call_handle_oracle_fulfillments.push(contract::AttesterHandleOracleFulfillmentCall {
+            call_tx_hash: Hex(&tx.hash).to_string(),
+            call_block_time: Some(blk.timestamp().to_owned()),
+            call_block_number: blk.number,
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_caller: call.caller.clone(),
+            call_value: crate::call_value(call),
+            call_gas_used: call.gas_consumed,
+            contract_address: call.address.clone(),
+            request_id: Vec::from(decoded_call.request_id),
+            response: decoded_call.response,
+            err: decoded_call.err,
+        });
+    } else if let Some(decoded_call) = abi::attester_contract::functions::SendRequest::match_and_decode(call) {
+        let Some(subscription_id) = crate::numeric::checked_u64(&decoded_call.subscription_id) else { substreams::log::info!("skipping attester call SendRequest at tx {} ordinal {}: subscription_id overflows 64 bits", Hex(&tx.hash), call.begin_ordinal); return; };
+        let Some(callback_gas_limit) = crate::numeric::checked_u64(&decoded_call.callback_gas_limit) else { substreams::log::info!("skipping attester call SendRequest at tx {} ordinal {}: callback_gas_limit overflows 64 bits", Hex(&tx.hash), call.begin_ordinal); return; };
+        calls.attester_call_send_requests.push(contract::AttesterSendRequestCall {
+            call_tx_hash: Hex(&tx.hash).to_string(),
+            call_block_time: Some(blk.timestamp().to_owned()),
+            call_block_number: blk.number,
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_caller: call.caller.clone(),
+            call_value: crate::call_value(call),
+            call_gas_used: call.gas_consumed,
+            contract_address: call.address.clone(),
+            args: decoded_call.args,
+            subscription_id,
+            callback_gas_limit,
+        });
+    } else if let Some(decoded_call) = abi::attester_contract::functions::SetDonId::match_and_decode(call) {
+        calls.attester_call_set_don_ids.push(contract::AttesterSetDonIdCall {
+            call_tx_hash: Hex(&tx.hash).to_string(),
+            call_block_time: Some(blk.timestamp().to_owned()),
+            call_block_number: blk.number,
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_caller: call.caller.clone(),
+            call_value: crate::call_value(call),
+            call_gas_used: call.gas_consumed,
+            contract_address: call.address.clone(),
+            new_don_id: Vec::from(decoded_call.new_don_id),
+        });
+    } else if let Some(decoded_call) = abi::attester_contract::functions::SetSource::match_and_decode(call) {
+        calls.attester_call_set_sources.push(contract::AttesterSetSourceCall {
+            call_tx_hash: Hex(&tx.hash).to_string(),
+            call_block_time: Some(blk.timestamp().to_owned()),
+            call_block_number: blk.number,
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_caller: call.caller.clone(),
+            call_value: crate::call_value(call),
+            call_gas_used: call.gas_consumed,
+            contract_address: call.address.clone(),
+            u_source: decoded_call.u_source,
+        });
+    } else if let Some(decoded_call) = abi::attester_contract::functions::TransferOwnership::match_and_decode(call) {
+        calls.attester_call_transfer_ownerships.push(contract::AttesterTransferOwnershipCall {
+            call_tx_hash: Hex(&tx.hash).to_string(),
+            call_block_time: Some(blk.timestamp().to_owned()),
+            call_block_number: blk.number,
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_caller: call.caller.clone(),
+            call_value: crate::call_value(call),
+            call_gas_used: call.gas_consumed,
+            contract_address: call.address.clone(),
+            to: decoded_call.to,
+        });
+    }
+}
+
+// Decodes `call` with the nft ABI function matching its selector and appends it to `calls`.
+fn map_nft_call(blk: &eth::Block, tx: &eth::TransactionTrace, call: &eth::Call, calls: &mut contract::Calls) {
+    if let Some(decoded_call) = abi::nft_contract::functions::Approve::match_and_decode(call) {
+        calls.nft_call_approves.push(contract::NftApproveCall {
+            call_tx_hash: Hex(&tx.hash).to_string(),
+            call_block_time: Some(blk.timestamp().to_owned()),
+            call_block_number: blk.number,
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_caller: call.caller.clone(),
+            call_value: crate::call_value(call),
+            call_gas_used: call.gas_consumed,
+            contract_address: call.address.clone(),
+            to: decoded_call.to,
+            token_id: decoded_call.token_id.to_string(),
+            token_id_bigint: Some((&decoded_call.token_id).into()),
+        });
+    } else if let Some(decoded_call) = abi::nft_contract::functions::Burn::match_and_decode(call) {
+        calls.nft_call_burns.push(contract::NftBurnCall {
+            call_tx_hash: Hex(&tx.hash).to_string(),
+            call_block_time: Some(blk.timestamp().to_owned()),
+            call_block_number: blk.number,
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_caller: call.caller.clone(),
+            call_value: crate::call_value(call),
+            call_gas_used: call.gas_consumed,
+            contract_address: call.address.clone(),
+            token_id: decoded_call.token_id.to_string(),
+            token_id_bigint: Some((&decoded_call.token_id).into()),
+        });
+    } else if let Some(decoded_call) = abi::nft_contract::functions::GrantRole::match_and_decode(call) {
+        calls.nft_call_grant_roles.push(contract::NftGrantRoleCall {
+            call_tx_hash: Hex(&tx.hash).to_string(),
+            call_block_time: Some(blk.timestamp().to_owned()),
+            call_block_number: blk.number,
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_caller: call.caller.clone(),
+            call_value: crate::call_value(call),
+            call_gas_used: call.gas_consumed,
+            contract_address: call.address.clone(),
+            role: Vec::from(decoded_call.role),
+            account: decoded_call.account,
+        });
+    } else if let Some(decoded_call) = abi::nft_contract::functions::RenounceRole::match_and_decode(call) {
+        calls.nft_call_renounce_roles.push(contract::NftRenounceRoleCall {
+            call_tx_hash: Hex(&tx.hash).to_string(),
+            call_block_time: Some(blk.timestamp().to_owned()),
+            call_block_number: blk.number,
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_caller: call.caller.clone(),
+            call_value: crate::call_value(call),
+            call_gas_used: call.gas_consumed,
+            contract_address: call.address.clone(),
+            role: Vec::from(decoded_call.role),
+            caller_confirmation: decoded_call.caller_confirmation,
+        });
+    } else if let Some(decoded_call) = abi::nft_contract::functions::RevokeRole::match_and_decode(call) {
+        calls.nft_call_revoke_roles.push(contract::NftRevokeRoleCall {
+            call_tx_hash: Hex(&tx.hash).to_string(),
+            call_block_time: Some(blk.timestamp().to_owned()),
+            call_block_number: blk.number,
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_caller: call.caller.clone(),
+            call_value: crate::call_value(call),
+            call_gas_used: call.gas_consumed,
+            contract_address: call.address.clone(),
+            role: Vec::from(decoded_call.role),
+            account: decoded_call.account,
+        });
+    } else if let Some(decoded_call) = abi::nft_contract::functions::SafeMint::match_and_decode(call) {
+        calls.nft_call_safe_mints.push(contract::NftSafeMintCall {
+            call_tx_hash: Hex(&tx.hash).to_string(),
+            call_block_time: Some(blk.timestamp().to_owned()),
+            call_block_number: blk.number,
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_caller: call.caller.clone(),
+            call_value: crate::call_value(call),
+            call_gas_used: call.gas_consumed,
+            contract_address: call.address.clone(),
+            to: decoded_call.to,
+            uri: decoded_call.uri,
+        });
+    } else if let Some(decoded_call) = abi::nft_contract::functions::SafeTransferFrom1::match_and_decode(call) {
+        calls.nft_call_safe_transfer_from_1s.push(contract::NftSafeTransferFrom1Call {
+            call_tx_hash: Hex(&tx.hash).to_string(),
+            call_block_time: Some(blk.timestamp().to_owned()),
+            call_block_number: blk.number,
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_caller: call.caller.clone(),
+            call_value: crate::call_value(call),
+            call_gas_used: call.gas_consumed,
+            contract_address: call.address.clone(),
+            from: decoded_call.from,
+            to: decoded_call.to,
+            token_id: decoded_call.token_id.to_string(),
+            token_id_bigint: Some((&decoded_call.token_id).into()),
+        });
+    } else if let Some(decoded_call) = abi::nft_contract::functions::SafeTransferFrom2::match_and_decode(call) {
+        calls.nft_call_safe_transfer_from_2s.push(contract::NftSafeTransferFrom2Call {
+            call_tx_hash: Hex(&tx.hash).to_string(),
+            call_block_time: Some(blk.timestamp().to_owned()),
+            call_block_number: blk.number,
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_caller: call.caller.clone(),
+            call_value: crate::call_value(call),
+            call_gas_used: call.gas_consumed,
+            contract_address: call.address.clone(),
+            from: decoded_call.from,
+            to: decoded_call.to,
+            token_id: decoded_call.token_id.to_string(),
+            data: decoded_call.data,
+            token_id_bigint: Some((&decoded_call.token_id).into()),
+        });
+    } else if let Some(decoded_call) = abi::nft_contract::functions::SetApprovalForAll::match_and_decode(call) {
+        calls.nft_call_set_approval_for_alls.push(contract::NftSetApprovalForAllCall {
+            call_tx_hash: Hex(&tx.hash).to_string(),
+            call_block_time: Some(blk.timestamp().to_owned()),
+            call_block_number: blk.number,
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_caller: call.caller.clone(),
+            call_value: crate::call_value(call),
+            call_gas_used: call.gas_consumed,
+            contract_address: call.address.clone(),
+            operator: decoded_call.operator,
+            approved: decoded_call.approved,
+        });
+    } else if let Some(decoded_call) = abi::nft_contract::functions::TransferFrom::match_and_decode(call) {
+        calls.nft_call_transfer_froms.push(contract::NftTransferFromCall {
+            call_tx_hash: Hex(&tx.hash).to_string(),
+            call_block_time: Some(blk.timestamp().to_owned()),
+            call_block_number: blk.number,
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_caller: call.caller.clone(),
+            call_value: crate::call_value(call),
+            call_gas_used: call.gas_consumed,
+            contract_address: call.address.clone(),
+            from: decoded_call.from,
+            to: decoded_call.to,
+            token_id: decoded_call.token_id.to_string(),
+            token_id_bigint: Some((&decoded_call.token_id).into()),
+        });
+    }
+}
+
+/// A custom error declared by one of the ABIs.
+pub struct AbiError {
+    pub name: &'static str,
+    pub inputs: Vec<(&'static str, ethabi::ParamType)>,
+}
+
+/// Name of the ABI function selected by the first four bytes of `input`.
+pub fn function_name(input: &[u8]) -> Option<&'static str> {
+    match input.get(..4)? {
+        [0x01, 0xff, 0xc9, 0xa7] => Some("supportsInterface"),
+        [0x06, 0xfd, 0xde, 0x03] => Some("name"),
+        [0x08, 0x18, 0x12, 0xfc] => Some("getApproved"),
+        [0x09, 0x5e, 0xa7, 0xb3] => Some("approve"),
+        [0x0c, 0xa7, 0x61, 0x75] => Some("handleOracleFulfillment"),
+        [0x23, 0xb8, 0x72, 0xdd] => Some("transferFrom"),
+        [0x24, 0x8a, 0x9c, 0xa3] => Some("getRoleAdmin"),
+        [0x2f, 0x2f, 0xf1, 0x5d] => Some("grantRole"),
+        [0x36, 0x56, 0x8a, 0xbe] => Some("renounceRole"),
+        [0x37, 0x33, 0xac, 0xf3] => Some("sendRequest"),
+        [0x39, 0x44, 0xea, 0x3a] => Some("s_lastResponse"),
+        [0x42, 0x84, 0x2e, 0x0e] => Some("safeTransferFrom"),
+        [0x42, 0x96, 0x6c, 0x68] => Some("burn"),
+        [0x4b, 0x07, 0x95, 0xa8] => Some("s_lastError"),
+        [0x63, 0x52, 0x21, 0x1e] => Some("ownerOf"),
+        [0x67, 0xe8, 0x28, 0xbf] => Some("source"),
+        [0x70, 0xa0, 0x82, 0x31] => Some("balanceOf"),
+        [0x78, 0xca, 0x5d, 0xe7] => Some("setDonId"),
+        [0x79, 0xba, 0x50, 0x97] => Some("acceptOwnership"),
+        [0x7d, 0x26, 0xaa, 0xa6] => Some("attesterOf"),
+        [0x85, 0x8e, 0x76, 0xe6] => Some("s_lastUrl"),
+        [0x8d, 0xa5, 0xcb, 0x5b] => Some("owner"),
+        [0x8d, 0xbe, 0x7b, 0x9d] => Some("donId"),
+        [0x91, 0xd1, 0x48, 0x54] => Some("hasRole"),
+        [0x95, 0xd8, 0x9b, 0x41] => Some("symbol"),
+        [0x99, 0xd2, 0x54, 0x55] => Some("setSource"),
+        [0xa2, 0x17, 0xfd, 0xdf] => Some("DEFAULT_ADMIN_ROLE"),
+        [0xa2, 0x2c, 0xb4, 0x65] => Some("setApprovalForAll"),
+        [0xb1, 0xe2, 0x17, 0x49] => Some("s_lastRequestId"),
+        [0xb8, 0x8d, 0x4f, 0xde] => Some("safeTransferFrom"),
+        [0xc8, 0x7b, 0x56, 0xdd] => Some("tokenURI"),
+        [0xd2, 0x04, 0xc4, 0x5e] => Some("safeMint"),
+        [0xd5, 0x39, 0x13, 0x93] => Some("MINTER_ROLE"),
+        [0xd5, 0x47, 0x74, 0x1f] => Some("revokeRole"),
+        [0xe9, 0x85, 0xe9, 0xc5] => Some("isApprovedForAll"),
+        [0xf2, 0xfd, 0xe3, 0x8b] => Some("transferOwnership"),
+        _ => None,
+    }
+}
+
+/// The ABI custom error selected by the first four bytes of revert `data`.
+pub fn custom_error(data: &[u8]) -> Option<AbiError> {
+    match data.get(..4)? {
+        [0x17, 0x7e, 0x80, 0x2f] => Some(AbiError { name: "ERC721InsufficientApproval", inputs: vec![("operator", ethabi::ParamType::Address), ("tokenId", ethabi::ParamType::Uint(256))] }),
+        [0x22, 0xce, 0x3e, 0xdd] => Some(AbiError { name: "EmptySource", inputs: vec![] }),
+        [0x5b, 0x08, 0xba, 0x18] => Some(AbiError { name: "ERC721InvalidOperator", inputs: vec![("operator", ethabi::ParamType::Address)] }),
+        [0x64, 0x28, 0x3d, 0x7b] => Some(AbiError { name: "ERC721IncorrectOwner", inputs: vec![("sender", ethabi::ParamType::Address), ("tokenId", ethabi::ParamType::Uint(256)), ("owner", ethabi::ParamType::Address)] }),
+        [0x64, 0xa0, 0xae, 0x92] => Some(AbiError { name: "ERC721InvalidReceiver", inputs: vec![("receiver", ethabi::ParamType::Address)] }),
+        [0x66, 0x97, 0xb2, 0x32] => Some(AbiError { name: "AccessControlBadConfirmation", inputs: vec![] }),
+        [0x73, 0xc6, 0xac, 0x6e] => Some(AbiError { name: "ERC721InvalidSender", inputs: vec![("sender", ethabi::ParamType::Address)] }),
+        [0x7e, 0x27, 0x32, 0x89] => Some(AbiError { name: "ERC721NonexistentToken", inputs: vec![("tokenId", ethabi::ParamType::Uint(256))] }),
+        [0x89, 0xc6, 0x2b, 0x64] => Some(AbiError { name: "ERC721InvalidOwner", inputs: vec![("owner", ethabi::ParamType::Address)] }),
+        [0xa8, 0x0d, 0x31, 0xf7] => Some(AbiError { name: "NoInlineSecrets", inputs: vec![] }),
+        [0xa9, 0xfb, 0xf5, 0x1f] => Some(AbiError { name: "ERC721InvalidApprover", inputs: vec![("approver", ethabi::ParamType::Address)] }),
+        [0xc6, 0x82, 0x9f, 0x83] => Some(AbiError { name: "OnlyRouterCanFulfill", inputs: vec![] }),
+        [0xe2, 0x51, 0x7d, 0x3f] => Some(AbiError { name: "AccessControlUnauthorizedAccount", inputs: vec![("account", ethabi::ParamType::Address), ("neededRole", ethabi::ParamType::FixedBytes(32))] }),
+        [0xfe, 0x93, 0x6c, 0xb7] => Some(AbiError { name: "EmptyArgs", inputs: vec![] }),
+        _ => None,
+    }
+}
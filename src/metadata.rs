@@ -0,0 +1,123 @@
+use crate::numeric;
+use crate::pb::contract::v1 as contract;
+use std::collections::HashSet;
+use substreams::errors::Error;
+use substreams::scalar::BigInt;
+
+/// Largest `BatchMetadataUpdate` range expanded into individual tokens, parsed from the
+/// `map_metadata_refreshes` params.
+pub fn parse_range_cap(params: &str) -> Result<u64, Error> {
+    params.trim().parse::<u64>().map_err(|_| {
+        Error::msg(format!(
+            "invalid params {:?}: expected the largest range to expand",
+            params
+        ))
+    })
+}
+
+/// Turns the block's ERC-4906 events into one refresh per token, in log order. Batch ranges
+/// are expanded unless they span more than `range_cap` tokens, in which case they are passed on
+/// whole: `BatchMetadataUpdate(0, type(uint256).max)` is the conventional "refresh everything".
+pub fn metadata_refreshes(
+    events: &contract::Events,
+    range_cap: u64,
+) -> contract::MetadataRefreshes {
+    let updates = events
+        .nft_metadata_updates
+        .iter()
+        .map(|evt| (evt.evt_index, Update::Token(evt)));
+    let batches = events
+        .nft_batch_metadata_updates
+        .iter()
+        .map(|evt| (evt.evt_index, Update::Batch(evt)));
+    let mut updates = updates.chain(batches).collect::<Vec<_>>();
+    updates.sort_by_key(|(evt_index, _)| *evt_index);
+
+    let mut refreshes = RefreshQueue::default();
+    let mut oversized_ranges = Vec::new();
+    for (_, update) in updates {
+        match update {
+            Update::Token(evt) => {
+                let refresh = contract::MetadataRefresh {
+                    contract_address: evt.contract_address.clone(),
+                    evt_tx_hash: evt.evt_tx_hash.clone(),
+                    evt_index: evt.evt_index,
+                    block_number: evt.evt_block_number,
+                    block_time: evt.evt_block_time,
+                    ..Default::default()
+                };
+                refreshes.push(numeric::big_int(&evt.u_token_id_bigint), &refresh);
+            }
+            Update::Batch(evt) => {
+                let from = numeric::big_int(&evt.u_from_token_id_bigint);
+                let to = numeric::big_int(&evt.u_to_token_id_bigint);
+                if to < from {
+                    continue;
+                }
+                if to.clone() - from.clone() >= BigInt::from(range_cap) {
+                    oversized_ranges.push(contract::MetadataRefreshRange {
+                        contract_address: evt.contract_address.clone(),
+                        from_token_id: evt.u_from_token_id.clone(),
+                        to_token_id: evt.u_to_token_id.clone(),
+                        from_token_id_bigint: evt.u_from_token_id_bigint.clone(),
+                        to_token_id_bigint: evt.u_to_token_id_bigint.clone(),
+                        evt_tx_hash: evt.evt_tx_hash.clone(),
+                        evt_index: evt.evt_index,
+                        block_number: evt.evt_block_number,
+                        block_time: evt.evt_block_time,
+                    });
+                    continue;
+                }
+
+                let refresh = contract::MetadataRefresh {
+                    contract_address: evt.contract_address.clone(),
+                    evt_tx_hash: evt.evt_tx_hash.clone(),
+                    evt_index: evt.evt_index,
+                    block_number: evt.evt_block_number,
+                    block_time: evt.evt_block_time,
+                    ..Default::default()
+                };
+                let mut token_id = from;
+                while token_id <= to {
+                    refreshes.push(token_id.clone(), &refresh);
+                    token_id = token_id + BigInt::one();
+                }
+            }
+        }
+    }
+
+    contract::MetadataRefreshes {
+        refreshes: refreshes.refreshes,
+        oversized_ranges,
+    }
+}
+
+enum Update<'a> {
+    Token(&'a contract::NftMetadataUpdate),
+    Batch(&'a contract::NftBatchMetadataUpdate),
+}
+
+// Keeps the first refresh of every token: the cache only needs to refetch it once per block.
+#[derive(Default)]
+struct RefreshQueue {
+    seen: HashSet<(Vec<u8>, String)>,
+    refreshes: Vec<contract::MetadataRefresh>,
+}
+
+impl RefreshQueue {
+    fn push(&mut self, token_id: BigInt, refresh: &contract::MetadataRefresh) {
+        let token_id_string = token_id.to_string();
+        if !self
+            .seen
+            .insert((refresh.contract_address.clone(), token_id_string.clone()))
+        {
+            return;
+        }
+
+        self.refreshes.push(contract::MetadataRefresh {
+            token_id: token_id_string,
+            token_id_bigint: Some((&token_id).into()),
+            ..refresh.clone()
+        });
+    }
+}
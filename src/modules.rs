@@ -0,0 +1,223 @@
+//! The body of every module of `substreams.yaml`, as a plain function of the module's inputs.
+//! The handlers of `lib.rs` only unwrap their inputs and call these, and so does the `replay`
+//! binary with its in-memory stores, so both run the same code.
+
+use crate::params::{RouterSubscription, TrackedContracts};
+use crate::pb::contract::v1 as contract;
+use crate::{
+    approvals, costs, db, failures, graph, index, mapping, metadata, oracle, ownership, provenance,
+    roles, router,
+};
+use substreams::errors::Error;
+use substreams::pb::sf::substreams::index::v1::Keys;
+use substreams::pb::substreams::Clock;
+use substreams::scalar::BigInt;
+use substreams::store::{Appender, DeltaBigInt, Deltas, StoreAdd, StoreGet, StoreSet};
+use substreams_database_change::pb::database::DatabaseChanges;
+use substreams_database_change::tables::Tables as DatabaseChangeTables;
+use substreams_entity_change::pb::entity::EntityChanges;
+use substreams_entity_change::tables::Tables as EntityChangesTables;
+use substreams_ethereum::pb::eth::v2 as eth;
+
+pub fn index_events(blk: &eth::Block) -> Result<Keys, Error> {
+    Ok(index::event_keys(blk))
+}
+
+pub fn index_calls(blk: &eth::Block) -> Result<Keys, Error> {
+    Ok(index::call_keys(blk))
+}
+
+pub fn map_events(params: &str, blk: &eth::Block) -> Result<contract::Events, Error> {
+    let contracts = TrackedContracts::parse(params, "evt_addr")?;
+    Ok(crate::map_tracked_events(blk, &contracts))
+}
+
+pub fn map_calls(params: &str, blk: &eth::Block) -> Result<contract::Calls, Error> {
+    let contracts = TrackedContracts::parse(params, "call_to")?;
+    let mut calls = contract::Calls::default();
+    mapping::map_calls(blk, &contracts, &mut calls);
+    Ok(calls)
+}
+
+pub fn map_failures(params: &str, blk: &eth::Block) -> Result<contract::FailedCalls, Error> {
+    let contracts = TrackedContracts::parse(params, "call_to")?;
+    Ok(failures::failed_calls(blk, &contracts))
+}
+
+pub fn map_router_events(
+    params: &str,
+    blk: &eth::Block,
+    request_steps: &impl StoreGet<contract::OracleRequestStep>,
+) -> Result<contract::Events, Error> {
+    let subscription = RouterSubscription::parse(params)?;
+    Ok(router::router_events(blk, &subscription, request_steps))
+}
+
+pub fn map_mints(
+    events: &contract::Events,
+    calls: &contract::Calls,
+) -> Result<contract::Mints, Error> {
+    Ok(crate::nft_mints(events, calls))
+}
+
+pub fn store_attestations(
+    events: &contract::Events,
+    store: &impl StoreSet<contract::AttesterAttested>,
+) {
+    provenance::set_attestations(events, store);
+}
+
+pub fn store_minted_photos(mints: &contract::Mints, store: &impl StoreSet<contract::NftMint>) {
+    provenance::set_minted_photos(mints, store);
+}
+
+pub fn map_provenance(
+    params: &str,
+    mints: &contract::Mints,
+    events: &contract::Events,
+    attestations: &impl StoreGet<contract::AttesterAttested>,
+    minted_photos: &impl StoreGet<contract::NftMint>,
+) -> Result<contract::Provenances, Error> {
+    let window = provenance::parse_window(params)?;
+    Ok(provenance::provenances(
+        window,
+        mints,
+        events,
+        attestations,
+        minted_photos,
+    ))
+}
+
+pub fn map_metadata_refreshes(
+    params: &str,
+    events: &contract::Events,
+) -> Result<contract::MetadataRefreshes, Error> {
+    let range_cap = metadata::parse_range_cap(params)?;
+    Ok(metadata::metadata_refreshes(events, range_cap))
+}
+
+pub fn db_out(events: &contract::Events) -> Result<DatabaseChanges, Error> {
+    // Initialize Database Changes container
+    let mut tables = DatabaseChangeTables::new();
+    db::db_nft_out(events, &mut tables);
+    db::db_attester_out(events, &mut tables);
+    db::db_tokens_out(events, &mut tables);
+    db::db_attestations_out(events, &mut tables);
+    Ok(tables.to_database_changes())
+}
+
+pub fn graph_out(events: &contract::Events) -> Result<EntityChanges, Error> {
+    // Initialize Entity Changes container
+    let mut tables = EntityChangesTables::new();
+    graph::graph_nft_out(events, &mut tables);
+    graph::graph_attester_out(events, &mut tables);
+    Ok(tables.to_entity_changes())
+}
+
+pub fn store_token_owners(events: &contract::Events, store: &impl StoreSet<String>) {
+    crate::set_token_owners(events, store);
+}
+
+pub fn store_balances(events: &contract::Events, store: &impl StoreAdd<BigInt>) {
+    crate::add_balances(events, store);
+}
+
+pub fn store_token_approvals(events: &contract::Events, store: &impl StoreSet<String>) {
+    approvals::set_token_approvals(events, store);
+}
+
+pub fn store_operator_approvals(events: &contract::Events, store: &impl Appender<String>) {
+    approvals::append_operator_changes(events, store);
+}
+
+pub fn map_operator_approvals(
+    events: &contract::Events,
+    operators: &impl StoreGet<Vec<String>>,
+) -> Result<contract::OperatorApprovals, Error> {
+    Ok(approvals::operator_approvals(events, operators))
+}
+
+pub fn store_oracle_request_steps(
+    events: &contract::Events,
+    store: &impl StoreSet<contract::OracleRequestStep>,
+) {
+    oracle::set_request_steps(events, store);
+}
+
+pub fn map_oracle_requests(
+    events: &contract::Events,
+    store: &impl StoreGet<contract::OracleRequestStep>,
+) -> Result<contract::OracleRequests, Error> {
+    Ok(oracle::oracle_requests(events, store))
+}
+
+pub fn store_role_members(events: &contract::Events, store: &impl Appender<String>) {
+    roles::append_member_changes(events, store);
+}
+
+pub fn store_role_admins(events: &contract::Events, store: &impl StoreSet<String>) {
+    roles::set_role_admins(events, store);
+}
+
+pub fn map_role_snapshots(
+    events: &contract::Events,
+    members: &impl StoreGet<Vec<String>>,
+    admins: &impl StoreGet<String>,
+) -> Result<contract::RoleSnapshots, Error> {
+    Ok(roles::role_snapshots(events, members, admins))
+}
+
+pub fn store_transaction_costs(
+    events: &contract::Events,
+    store: &impl StoreSet<contract::TransactionCost>,
+) {
+    costs::set_transaction_costs(events, store);
+}
+
+pub fn map_attestation_costs(
+    events: &contract::Events,
+    store: &impl StoreGet<contract::TransactionCost>,
+) -> Result<contract::AttestationCosts, Error> {
+    Ok(costs::attestation_costs(events, store))
+}
+
+pub fn store_daily_costs(events: &contract::Events, store: &impl StoreAdd<BigInt>) {
+    costs::add_daily_costs(events, store);
+}
+
+pub fn store_attester_ownership(
+    events: &contract::Events,
+    store: &impl StoreSet<contract::AttesterOwnership>,
+) {
+    ownership::set_ownerships(events, store);
+}
+
+pub fn store_transfer_requests(events: &contract::Events, store: &impl Appender<String>) {
+    ownership::append_transfer_requests(events, store);
+}
+
+pub fn map_attester_ownership(
+    events: &contract::Events,
+    ownerships: &impl StoreGet<contract::AttesterOwnership>,
+) -> Result<contract::AttesterOwnerships, Error> {
+    Ok(ownership::attester_ownerships(events, ownerships))
+}
+
+pub fn map_stale_transfers(
+    params: &str,
+    clock: &Clock,
+    ownerships: &impl StoreGet<contract::AttesterOwnership>,
+    requests: &impl StoreGet<Vec<String>>,
+) -> Result<contract::OwnershipAlerts, Error> {
+    let alert_after = ownership::parse_alert_after(params)?;
+    Ok(ownership::stale_transfers(
+        clock.number,
+        alert_after,
+        ownerships,
+        requests,
+    ))
+}
+
+pub fn map_balance_changes(deltas: Deltas<DeltaBigInt>) -> Result<contract::BalanceChanges, Error> {
+    crate::balance_changes(deltas)
+}
@@ -0,0 +1,54 @@
+use crate::pb::contract::v1 as contract;
+use substreams::scalar::BigInt;
+use substreams::Hex;
+
+impl From<&BigInt> for contract::BigInt {
+    fn from(value: &BigInt) -> Self {
+        let (_, mut be_bytes) = value.to_bytes_be();
+        let leading_zeros = be_bytes.iter().take_while(|byte| **byte == 0).count();
+        be_bytes.drain(..leading_zeros);
+
+        let u64_value = (be_bytes.len() <= 8).then(|| {
+            be_bytes
+                .iter()
+                .fold(0u64, |acc, byte| acc << 8 | *byte as u64)
+        });
+        contract::BigInt {
+            be_bytes,
+            u64_value,
+        }
+    }
+}
+
+impl From<&contract::BigInt> for BigInt {
+    fn from(value: &contract::BigInt) -> Self {
+        match value.u64_value {
+            Some(value) => BigInt::from(value),
+            None => BigInt::from_unsigned_bytes_be(&value.be_bytes),
+        }
+    }
+}
+
+/// `value` as a `u64`, or `None` when it does not fit, as `u64_value` is computed.
+pub fn checked_u64(value: &BigInt) -> Option<u64> {
+    contract::BigInt::from(value).u64_value
+}
+
+/// The value of an optional `BigInt` field, zero when unset.
+pub fn big_int(value: &Option<contract::BigInt>) -> BigInt {
+    value
+        .as_ref()
+        .map(BigInt::from)
+        .unwrap_or_else(BigInt::zero)
+}
+
+/// Renders a 256-bit value as fixed-width hex so that store keys sort in numeric order.
+pub fn sortable_key(value: &Option<contract::BigInt>) -> String {
+    let be_bytes = value
+        .as_ref()
+        .map(|value| value.be_bytes.as_slice())
+        .unwrap_or_default();
+    let mut padded = [0u8; 32];
+    padded[32 - be_bytes.len()..].copy_from_slice(be_bytes);
+    Hex(padded).to_string()
+}
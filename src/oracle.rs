@@ -0,0 +1,192 @@
+use crate::pb::contract::v1 as contract;
+use contract::oracle_request::Status;
+use prost_types::Timestamp;
+use substreams::store::{StoreGet, StoreSet};
+use substreams::Hex;
+
+const SENT: &str = "sent";
+const RESPONDED: &str = "responded";
+const ERRORED: &str = "errored";
+const FULFILLED: &str = "fulfilled";
+const ATTESTED: &str = "attested";
+
+/// Records the block and transaction of every lifecycle step of a Chainlink Functions request.
+/// An errored `Response` is recorded both as `responded` and `errored`.
+pub fn set_request_steps(
+    events: &contract::Events,
+    store: &impl StoreSet<contract::OracleRequestStep>,
+) {
+    for evt in &events.attester_request_sents {
+        let step = request_step(&evt.evt_tx_hash, evt.evt_block_number, &evt.evt_block_time);
+        store.set(evt.evt_index as u64, request_step_key(&evt.id, SENT), &step);
+    }
+    for evt in &events.attester_responses {
+        let step = request_step(&evt.evt_tx_hash, evt.evt_block_number, &evt.evt_block_time);
+        store.set(
+            evt.evt_index as u64,
+            request_step_key(&evt.request_id, RESPONDED),
+            &step,
+        );
+        if !evt.err.is_empty() {
+            store.set(
+                evt.evt_index as u64,
+                request_step_key(&evt.request_id, ERRORED),
+                &step,
+            );
+        }
+    }
+    for evt in &events.attester_request_fulfilleds {
+        let step = request_step(&evt.evt_tx_hash, evt.evt_block_number, &evt.evt_block_time);
+        store.set(
+            evt.evt_index as u64,
+            request_step_key(&evt.id, FULFILLED),
+            &step,
+        );
+    }
+    for evt in &events.attester_attesteds {
+        let step = request_step(&evt.evt_tx_hash, evt.evt_block_number, &evt.evt_block_time);
+        store.set(
+            evt.evt_index as u64,
+            request_step_key(&evt.request_id, ATTESTED),
+            &step,
+        );
+    }
+}
+
+/// Whether the attester sent `request_id`, in this block or an earlier one.
+pub fn was_sent(request_id: &[u8], store: &impl StoreGet<contract::OracleRequestStep>) -> bool {
+    store.has_last(request_step_key(request_id, SENT))
+}
+
+/// Ids of the requests that moved to a new step in this block, in first-seen order.
+pub fn touched_request_ids(events: &contract::Events) -> Vec<Vec<u8>> {
+    let mut ids: Vec<Vec<u8>> = Vec::new();
+    let candidates = events
+        .attester_request_sents
+        .iter()
+        .map(|evt| &evt.id)
+        .chain(events.attester_responses.iter().map(|evt| &evt.request_id))
+        .chain(events.attester_request_fulfilleds.iter().map(|evt| &evt.id))
+        .chain(events.attester_attesteds.iter().map(|evt| &evt.request_id));
+    for id in candidates {
+        if !ids.contains(id) {
+            ids.push(id.clone());
+        }
+    }
+    ids
+}
+
+/// The lifecycle of every request that moved to a new step in this block.
+pub fn oracle_requests(
+    events: &contract::Events,
+    store: &impl StoreGet<contract::OracleRequestStep>,
+) -> contract::OracleRequests {
+    let requests = touched_request_ids(events)
+        .iter()
+        .map(|request_id| oracle_request(request_id, store))
+        .collect();
+
+    contract::OracleRequests { requests }
+}
+
+/// Rebuilds the full lifecycle of a request from its recorded steps, with every step's
+/// latency measured from the `RequestSent` step when it is known.
+pub fn oracle_request(
+    request_id: &[u8],
+    store: &impl StoreGet<contract::OracleRequestStep>,
+) -> contract::OracleRequest {
+    let sent = store.get_last(request_step_key(request_id, SENT));
+    let with_latency = |step: Option<contract::OracleRequestStep>| {
+        step.map(|mut step| {
+            if let Some(sent) = &sent {
+                step.latency_blocks = step.block_number.saturating_sub(sent.block_number);
+                step.latency_seconds =
+                    seconds(&step.block_time).saturating_sub(seconds(&sent.block_time));
+            }
+            step
+        })
+    };
+
+    let responded = with_latency(store.get_last(request_step_key(request_id, RESPONDED)));
+    let fulfilled = with_latency(store.get_last(request_step_key(request_id, FULFILLED)));
+    let attested = with_latency(store.get_last(request_step_key(request_id, ATTESTED)));
+
+    let status = if store.has_last(request_step_key(request_id, ERRORED)) {
+        Status::Errored
+    } else if fulfilled.is_some() || attested.is_some() {
+        Status::Fulfilled
+    } else if responded.is_some() {
+        Status::Responded
+    } else {
+        Status::Pending
+    };
+
+    contract::OracleRequest {
+        request_id: request_id.to_vec(),
+        status: status as i32,
+        sent,
+        responded,
+        fulfilled,
+        attested,
+    }
+}
+
+/// Fills in the text rendering of each response payload and error.
+pub fn decode_responses(responses: &mut [contract::AttesterResponse]) {
+    for response in responses {
+        response.response_text = response_text(&response.response);
+        response.error_text = error_text(&response.err);
+        response.is_error = !response.err.is_empty();
+    }
+}
+
+/// Decodes a Chainlink Functions response payload. Sources returning `Functions.encodeString`
+/// produce raw UTF-8 while others ABI-encode a `string`; both are accepted and anything else
+/// is rendered lossily rather than dropped.
+pub fn response_text(response: &[u8]) -> String {
+    if let Ok(tokens) = ethabi::decode(&[ethabi::ParamType::String], response) {
+        if let Some(text) = tokens
+            .into_iter()
+            .next()
+            .and_then(|token| token.into_string())
+        {
+            return printable(&text);
+        }
+    }
+
+    printable(&String::from_utf8_lossy(response))
+}
+
+/// Decodes the error reported by the DON, which is plain UTF-8 text.
+pub fn error_text(err: &[u8]) -> String {
+    printable(&String::from_utf8_lossy(err))
+}
+
+// NUL bytes are valid UTF-8 but rejected by Postgres `TEXT` columns.
+fn printable(text: &str) -> String {
+    text.replace('\0', "")
+}
+
+fn request_step(
+    tx_hash: &str,
+    block_number: u64,
+    block_time: &Option<Timestamp>,
+) -> contract::OracleRequestStep {
+    contract::OracleRequestStep {
+        tx_hash: tx_hash.to_string(),
+        block_number,
+        block_time: *block_time,
+        ..Default::default()
+    }
+}
+
+fn request_step_key(request_id: &[u8], step: &str) -> String {
+    format!("request:{}:{}", Hex(request_id), step)
+}
+
+fn seconds(block_time: &Option<Timestamp>) -> u64 {
+    block_time
+        .as_ref()
+        .map(|time| time.seconds.max(0) as u64)
+        .unwrap_or_default()
+}
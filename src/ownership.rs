@@ -0,0 +1,142 @@
+use crate::pb::contract::v1 as contract;
+use substreams::errors::Error;
+use substreams::store::{Appender, StoreGet, StoreSet};
+use substreams::Hex;
+
+const TRANSFER_REQUESTS_KEY: &str = "transfer_requests";
+
+/// Number of blocks a transfer may stay unaccepted before it is reported, parsed from the
+/// `map_stale_transfers` params.
+pub fn parse_alert_after(params: &str) -> Result<u64, Error> {
+    match params.trim().parse::<u64>() {
+        Ok(blocks) if blocks > 0 => Ok(blocks),
+        _ => Err(Error::msg(format!(
+            "invalid params {:?}: expected a number of blocks above zero",
+            params
+        ))),
+    }
+}
+
+/// Records the ownership of every attester deployment touched in this block. ConfirmedOwner
+/// transfers in two steps: `OwnershipTransferRequested` names the pending owner, whose
+/// `acceptOwnership` emits `OwnershipTransferred`. The request's `from` is the current owner, so
+/// each event carries the whole state and the store never needs its previous value.
+pub fn set_ownerships(
+    events: &contract::Events,
+    store: &impl StoreSet<contract::AttesterOwnership>,
+) {
+    let requested = events
+        .attester_ownership_transfer_requesteds
+        .iter()
+        .map(|evt| {
+            let ownership = contract::AttesterOwnership {
+                contract_address: evt.contract_address.clone(),
+                owner: evt.from.clone(),
+                pending_owner: evt.to.clone(),
+                requested_tx_hash: evt.evt_tx_hash.clone(),
+                requested_block_number: evt.evt_block_number,
+                requested_block_time: evt.evt_block_time,
+                updated_block_number: evt.evt_block_number,
+            };
+            (evt.evt_index, ownership)
+        });
+    let transferred = events.attester_ownership_transferreds.iter().map(|evt| {
+        let ownership = contract::AttesterOwnership {
+            contract_address: evt.contract_address.clone(),
+            owner: evt.to.clone(),
+            updated_block_number: evt.evt_block_number,
+            ..Default::default()
+        };
+        (evt.evt_index, ownership)
+    });
+
+    let mut changes = requested.chain(transferred).collect::<Vec<_>>();
+    changes.sort_by_key(|(evt_index, _)| *evt_index);
+    for (evt_index, ownership) in changes {
+        store.set(
+            evt_index as u64,
+            ownership_key(&ownership.contract_address),
+            &ownership,
+        );
+    }
+}
+
+/// Lists the deployments that had a transfer requested, under a single key, so that the stale
+/// transfer check can go through them without enumerating the ownership store. A deployment is
+/// listed once per request; transfers are rare enough for the list to stay short.
+pub fn append_transfer_requests(events: &contract::Events, store: &impl Appender<String>) {
+    for evt in &events.attester_ownership_transfer_requesteds {
+        store.append(
+            evt.evt_index as u64,
+            TRANSFER_REQUESTS_KEY,
+            Hex(&evt.contract_address).to_string(),
+        );
+    }
+}
+
+/// An alert for every transfer still pending exactly `alert_after` blocks after its request. The
+/// check runs on every block, whether or not it holds tracked events, so a transfer is reported
+/// once, on its deadline, and a new request of the same deployment starts the count over.
+pub fn stale_transfers(
+    block_number: u64,
+    alert_after: u64,
+    ownerships: &impl StoreGet<contract::AttesterOwnership>,
+    requests: &impl StoreGet<Vec<String>>,
+) -> contract::OwnershipAlerts {
+    let mut contracts = requests.get_last(TRANSFER_REQUESTS_KEY).unwrap_or_default();
+    contracts.sort();
+    contracts.dedup();
+
+    let alerts = contracts
+        .iter()
+        .filter_map(|contract| Hex::decode(contract).ok())
+        .filter_map(|contract| ownerships.get_last(ownership_key(&contract)))
+        // Accepted transfers no longer have a pending owner.
+        .filter(|ownership| !ownership.pending_owner.is_empty())
+        .filter(|ownership| {
+            ownership.requested_block_number.saturating_add(alert_after) == block_number
+        })
+        .map(|ownership| contract::OwnershipAlert {
+            ownership: Some(ownership),
+            blocks_pending: alert_after,
+        })
+        .collect();
+    contract::OwnershipAlerts { alerts }
+}
+
+/// The ownership of the deployments touched in this block.
+pub fn attester_ownerships(
+    events: &contract::Events,
+    ownerships: &impl StoreGet<contract::AttesterOwnership>,
+) -> contract::AttesterOwnerships {
+    let mut contracts = events
+        .attester_ownership_transfer_requesteds
+        .iter()
+        .map(|evt| (evt.evt_index, &evt.contract_address))
+        .chain(
+            events
+                .attester_ownership_transferreds
+                .iter()
+                .map(|evt| (evt.evt_index, &evt.contract_address)),
+        )
+        .collect::<Vec<_>>();
+    contracts.sort_by_key(|(evt_index, _)| *evt_index);
+
+    let mut touched: Vec<&Vec<u8>> = Vec::new();
+    for (_, contract) in contracts {
+        if !touched.contains(&contract) {
+            touched.push(contract);
+        }
+    }
+
+    contract::AttesterOwnerships {
+        ownerships: touched
+            .into_iter()
+            .filter_map(|contract| ownerships.get_last(ownership_key(contract)))
+            .collect(),
+    }
+}
+
+fn ownership_key(contract_address: &[u8]) -> String {
+    format!("ownership:{}", Hex(contract_address))
+}
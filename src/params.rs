@@ -0,0 +1,130 @@
+use substreams::errors::Error;
+use substreams::Hex;
+
+// Block index keys of `index_events` and `index_calls` that do not name a contract.
+const SIGNATURE_KEYS: [&str; 2] = ["evt_sig", "call_method"];
+
+/// Contracts to index, parsed from a module's params.
+///
+/// The params are the module's block filter expression as well (`query: params: true`), so
+/// they take the form `evt_addr:0x<nft> || evt_addr:0x<attester>` for `map_events` and
+/// `call_to:0x<nft> || call_to:0x<attester>` for `map_calls`. Addresses are not tied to a
+/// contract kind: NFT and attester events and functions have distinct signatures, so the
+/// decoders tell them apart.
+///
+/// Addresses are lowercase hex, as the index keys are, and anything else is rejected.
+///
+/// The query may also narrow the blocks on signatures, e.g.
+/// `(evt_addr:0x<nft> || evt_addr:0x<attester>) && evt_sig:0x<topic0>`. Signature terms only
+/// concern the block filter and are skipped here.
+pub struct TrackedContracts {
+    addresses: Vec<Vec<u8>>,
+}
+
+impl TrackedContracts {
+    /// Parses `params`, accepting `<key>:0x<address>` and signature terms joined by `||` or `&&`.
+    pub fn parse(params: &str, key: &str) -> Result<Self, Error> {
+        let mut addresses = Vec::new();
+        let terms = params.split("||").flat_map(|terms| terms.split("&&"));
+        for term in
+            terms.map(|term| term.trim_matches(|c: char| c.is_whitespace() || c == '(' || c == ')'))
+        {
+            if SIGNATURE_KEYS
+                .iter()
+                .any(|signature_key| term.starts_with(&format!("{}:", signature_key)))
+            {
+                continue;
+            }
+
+            let address = term
+                .strip_prefix(key)
+                .and_then(|rest| rest.strip_prefix(':'))
+                .ok_or_else(|| {
+                    Error::msg(format!(
+                        "invalid params term {:?}: expected `{}:0x<address>`",
+                        term, key
+                    ))
+                })?;
+            addresses.push(parse_address(address)?);
+        }
+        if addresses.is_empty() {
+            return Err(Error::msg(format!(
+                "invalid params {:?}: expected at least one `{}:0x<address>`",
+                params, key
+            )));
+        }
+
+        Ok(TrackedContracts { addresses })
+    }
+
+    pub fn contains(&self, address: &[u8]) -> bool {
+        self.addresses
+            .iter()
+            .any(|tracked| tracked.as_slice() == address)
+    }
+}
+
+/// Functions router and subscription to follow, parsed from the `map_router_events` params:
+/// `router:0x<address>`, optionally followed by `&& subscription:<id>`.
+pub struct RouterSubscription {
+    pub router: Vec<u8>,
+    pub subscription_id: Option<u64>,
+}
+
+impl RouterSubscription {
+    pub fn parse(params: &str) -> Result<Self, Error> {
+        let mut router = None;
+        let mut subscription_id = None;
+        for term in params.split("&&").map(str::trim) {
+            if let Some(address) = term.strip_prefix("router:") {
+                router = Some(parse_address(address)?);
+            } else if let Some(id) = term.strip_prefix("subscription:") {
+                subscription_id = Some(id.parse::<u64>().map_err(|err| {
+                    Error::msg(format!(
+                        "invalid subscription id {:?} in params: {}",
+                        id, err
+                    ))
+                })?);
+            } else {
+                return Err(Error::msg(format!(
+                    "invalid params term {:?}: expected `router:0x<address>` or `subscription:<id>`",
+                    term
+                )));
+            }
+        }
+
+        match router {
+            Some(router) => Ok(RouterSubscription {
+                router,
+                subscription_id,
+            }),
+            None => Err(Error::msg(format!(
+                "invalid params {:?}: expected `router:0x<address>`, optionally `&& subscription:<id>`",
+                params
+            ))),
+        }
+    }
+}
+
+// Addresses must be lowercase: the params double as the block filter query, and the block index
+// keys are lowercase hex, so a checksummed address would silently match no block.
+fn parse_address(address: &str) -> Result<Vec<u8>, Error> {
+    let hex = address.strip_prefix("0x").unwrap_or(address);
+    if hex.chars().any(|c| c.is_ascii_uppercase()) {
+        return Err(Error::msg(format!(
+            "invalid address {:?} in params: expected lowercase hex, as in the block index keys",
+            address
+        )));
+    }
+    let bytes = Hex::decode(hex)
+        .map_err(|err| Error::msg(format!("invalid address {:?} in params: {}", address, err)))?;
+    if bytes.len() != 20 {
+        return Err(Error::msg(format!(
+            "invalid address {:?} in params: expected 20 bytes, got {}",
+            address,
+            bytes.len()
+        )));
+    }
+
+    Ok(bytes)
+}
@@ -1,5 +1,6 @@
 // @generated
 // This file is @generated by prost-build.
+#[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Events {
@@ -31,7 +32,30 @@ pub struct Events {
     pub attester_request_sents: ::prost::alloc::vec::Vec<AttesterRequestSent>,
     #[prost(message, repeated, tag="14")]
     pub attester_responses: ::prost::alloc::vec::Vec<AttesterResponse>,
+    #[prost(message, repeated, tag="15")]
+    pub router_request_processeds: ::prost::alloc::vec::Vec<RouterRequestProcessed>,
+    #[prost(message, repeated, tag="16")]
+    pub router_request_starts: ::prost::alloc::vec::Vec<RouterRequestStart>,
+    #[prost(message, repeated, tag="17")]
+    pub router_subscription_canceleds: ::prost::alloc::vec::Vec<RouterSubscriptionCanceled>,
+    #[prost(message, repeated, tag="18")]
+    pub router_subscription_consumer_addeds: ::prost::alloc::vec::Vec<RouterSubscriptionConsumerAdded>,
+    #[prost(message, repeated, tag="19")]
+    pub router_subscription_consumer_removeds: ::prost::alloc::vec::Vec<RouterSubscriptionConsumerRemoved>,
+    #[prost(message, repeated, tag="20")]
+    pub router_subscription_createds: ::prost::alloc::vec::Vec<RouterSubscriptionCreated>,
+    #[prost(message, repeated, tag="21")]
+    pub router_subscription_fundeds: ::prost::alloc::vec::Vec<RouterSubscriptionFunded>,
+    #[prost(message, repeated, tag="22")]
+    pub router_subscription_owner_transfer_requesteds: ::prost::alloc::vec::Vec<RouterSubscriptionOwnerTransferRequested>,
+    #[prost(message, repeated, tag="23")]
+    pub router_subscription_owner_transferreds: ::prost::alloc::vec::Vec<RouterSubscriptionOwnerTransferred>,
+    #[prost(message, repeated, tag="24")]
+    pub router_request_not_processeds: ::prost::alloc::vec::Vec<RouterRequestNotProcessed>,
+    #[prost(message, repeated, tag="25")]
+    pub router_request_timed_outs: ::prost::alloc::vec::Vec<RouterRequestTimedOut>,
 }
+#[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct NftApproval {
@@ -40,16 +64,27 @@ pub struct NftApproval {
     #[prost(uint32, tag="2")]
     pub evt_index: u32,
     #[prost(message, optional, tag="3")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
     pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
     #[prost(uint64, tag="4")]
     pub evt_block_number: u64,
     #[prost(bytes="vec", tag="5")]
+    #[serde(serialize_with = "crate::serialize::hex")]
     pub owner: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes="vec", tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex")]
     pub approved: ::prost::alloc::vec::Vec<u8>,
     #[prost(string, tag="7")]
     pub token_id: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="8")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="9")]
+    pub token_id_bigint: ::core::option::Option<BigInt>,
+    #[prost(message, optional, tag="10")]
+    pub evt_context: ::core::option::Option<EventContext>,
 }
+#[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct NftApprovalForAll {
@@ -58,16 +93,25 @@ pub struct NftApprovalForAll {
     #[prost(uint32, tag="2")]
     pub evt_index: u32,
     #[prost(message, optional, tag="3")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
     pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
     #[prost(uint64, tag="4")]
     pub evt_block_number: u64,
     #[prost(bytes="vec", tag="5")]
+    #[serde(serialize_with = "crate::serialize::hex")]
     pub owner: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes="vec", tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex")]
     pub operator: ::prost::alloc::vec::Vec<u8>,
     #[prost(bool, tag="7")]
     pub approved: bool,
+    #[prost(bytes="vec", tag="8")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="9")]
+    pub evt_context: ::core::option::Option<EventContext>,
 }
+#[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct NftBatchMetadataUpdate {
@@ -76,6 +120,7 @@ pub struct NftBatchMetadataUpdate {
     #[prost(uint32, tag="2")]
     pub evt_index: u32,
     #[prost(message, optional, tag="3")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
     pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
     #[prost(uint64, tag="4")]
     pub evt_block_number: u64,
@@ -83,7 +128,17 @@ pub struct NftBatchMetadataUpdate {
     pub u_from_token_id: ::prost::alloc::string::String,
     #[prost(string, tag="6")]
     pub u_to_token_id: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="7")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="8")]
+    pub u_from_token_id_bigint: ::core::option::Option<BigInt>,
+    #[prost(message, optional, tag="9")]
+    pub u_to_token_id_bigint: ::core::option::Option<BigInt>,
+    #[prost(message, optional, tag="10")]
+    pub evt_context: ::core::option::Option<EventContext>,
 }
+#[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct NftMetadataUpdate {
@@ -92,12 +147,21 @@ pub struct NftMetadataUpdate {
     #[prost(uint32, tag="2")]
     pub evt_index: u32,
     #[prost(message, optional, tag="3")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
     pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
     #[prost(uint64, tag="4")]
     pub evt_block_number: u64,
     #[prost(string, tag="5")]
     pub u_token_id: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="7")]
+    pub u_token_id_bigint: ::core::option::Option<BigInt>,
+    #[prost(message, optional, tag="8")]
+    pub evt_context: ::core::option::Option<EventContext>,
 }
+#[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct NftRoleAdminChanged {
@@ -106,16 +170,26 @@ pub struct NftRoleAdminChanged {
     #[prost(uint32, tag="2")]
     pub evt_index: u32,
     #[prost(message, optional, tag="3")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
     pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
     #[prost(uint64, tag="4")]
     pub evt_block_number: u64,
     #[prost(bytes="vec", tag="5")]
+    #[serde(serialize_with = "crate::serialize::hex")]
     pub role: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes="vec", tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex")]
     pub previous_admin_role: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes="vec", tag="7")]
+    #[serde(serialize_with = "crate::serialize::hex")]
     pub new_admin_role: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="9")]
+    pub evt_context: ::core::option::Option<EventContext>,
 }
+#[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct NftRoleGranted {
@@ -124,16 +198,26 @@ pub struct NftRoleGranted {
     #[prost(uint32, tag="2")]
     pub evt_index: u32,
     #[prost(message, optional, tag="3")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
     pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
     #[prost(uint64, tag="4")]
     pub evt_block_number: u64,
     #[prost(bytes="vec", tag="5")]
+    #[serde(serialize_with = "crate::serialize::hex")]
     pub role: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes="vec", tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex")]
     pub account: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes="vec", tag="7")]
+    #[serde(serialize_with = "crate::serialize::hex")]
     pub sender: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="9")]
+    pub evt_context: ::core::option::Option<EventContext>,
 }
+#[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct NftRoleRevoked {
@@ -142,16 +226,26 @@ pub struct NftRoleRevoked {
     #[prost(uint32, tag="2")]
     pub evt_index: u32,
     #[prost(message, optional, tag="3")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
     pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
     #[prost(uint64, tag="4")]
     pub evt_block_number: u64,
     #[prost(bytes="vec", tag="5")]
+    #[serde(serialize_with = "crate::serialize::hex")]
     pub role: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes="vec", tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex")]
     pub account: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes="vec", tag="7")]
+    #[serde(serialize_with = "crate::serialize::hex")]
     pub sender: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="9")]
+    pub evt_context: ::core::option::Option<EventContext>,
 }
+#[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct NftTransfer {
@@ -160,16 +254,27 @@ pub struct NftTransfer {
     #[prost(uint32, tag="2")]
     pub evt_index: u32,
     #[prost(message, optional, tag="3")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
     pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
     #[prost(uint64, tag="4")]
     pub evt_block_number: u64,
     #[prost(bytes="vec", tag="5")]
+    #[serde(serialize_with = "crate::serialize::hex")]
     pub from: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes="vec", tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex")]
     pub to: ::prost::alloc::vec::Vec<u8>,
     #[prost(string, tag="7")]
     pub token_id: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="8")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="9")]
+    pub token_id_bigint: ::core::option::Option<BigInt>,
+    #[prost(message, optional, tag="10")]
+    pub evt_context: ::core::option::Option<EventContext>,
 }
+#[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AttesterAttested {
@@ -178,18 +283,27 @@ pub struct AttesterAttested {
     #[prost(uint32, tag="2")]
     pub evt_index: u32,
     #[prost(message, optional, tag="3")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
     pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
     #[prost(uint64, tag="4")]
     pub evt_block_number: u64,
     #[prost(bytes="vec", tag="5")]
+    #[serde(serialize_with = "crate::serialize::hex")]
     pub request_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes="vec", tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex")]
     pub owner: ::prost::alloc::vec::Vec<u8>,
     #[prost(string, tag="7")]
     pub attester: ::prost::alloc::string::String,
     #[prost(string, tag="8")]
     pub url: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="9")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="10")]
+    pub evt_context: ::core::option::Option<EventContext>,
 }
+#[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AttesterOwnershipTransferRequested {
@@ -198,14 +312,23 @@ pub struct AttesterOwnershipTransferRequested {
     #[prost(uint32, tag="2")]
     pub evt_index: u32,
     #[prost(message, optional, tag="3")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
     pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
     #[prost(uint64, tag="4")]
     pub evt_block_number: u64,
     #[prost(bytes="vec", tag="5")]
+    #[serde(serialize_with = "crate::serialize::hex")]
     pub from: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes="vec", tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex")]
     pub to: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="8")]
+    pub evt_context: ::core::option::Option<EventContext>,
 }
+#[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AttesterOwnershipTransferred {
@@ -214,14 +337,23 @@ pub struct AttesterOwnershipTransferred {
     #[prost(uint32, tag="2")]
     pub evt_index: u32,
     #[prost(message, optional, tag="3")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
     pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
     #[prost(uint64, tag="4")]
     pub evt_block_number: u64,
     #[prost(bytes="vec", tag="5")]
+    #[serde(serialize_with = "crate::serialize::hex")]
     pub from: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes="vec", tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex")]
     pub to: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="8")]
+    pub evt_context: ::core::option::Option<EventContext>,
 }
+#[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AttesterRequestFulfilled {
@@ -230,12 +362,20 @@ pub struct AttesterRequestFulfilled {
     #[prost(uint32, tag="2")]
     pub evt_index: u32,
     #[prost(message, optional, tag="3")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
     pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
     #[prost(uint64, tag="4")]
     pub evt_block_number: u64,
     #[prost(bytes="vec", tag="5")]
+    #[serde(serialize_with = "crate::serialize::hex")]
     pub id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="7")]
+    pub evt_context: ::core::option::Option<EventContext>,
 }
+#[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AttesterRequestSent {
@@ -244,12 +384,20 @@ pub struct AttesterRequestSent {
     #[prost(uint32, tag="2")]
     pub evt_index: u32,
     #[prost(message, optional, tag="3")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
     pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
     #[prost(uint64, tag="4")]
     pub evt_block_number: u64,
     #[prost(bytes="vec", tag="5")]
+    #[serde(serialize_with = "crate::serialize::hex")]
     pub id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="7")]
+    pub evt_context: ::core::option::Option<EventContext>,
 }
+#[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AttesterResponse {
@@ -258,14 +406,1409 @@ pub struct AttesterResponse {
     #[prost(uint32, tag="2")]
     pub evt_index: u32,
     #[prost(message, optional, tag="3")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
     pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
     #[prost(uint64, tag="4")]
     pub evt_block_number: u64,
     #[prost(bytes="vec", tag="5")]
+    #[serde(serialize_with = "crate::serialize::hex")]
     pub request_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes="vec", tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex")]
     pub response: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes="vec", tag="7")]
+    #[serde(serialize_with = "crate::serialize::hex")]
     pub err: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="8")]
+    pub response_text: ::prost::alloc::string::String,
+    #[prost(string, tag="9")]
+    pub error_text: ::prost::alloc::string::String,
+    #[prost(bool, tag="10")]
+    pub is_error: bool,
+    #[prost(bytes="vec", tag="11")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="12")]
+    pub evt_context: ::core::option::Option<EventContext>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RouterRequestNotProcessed {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(bytes="vec", tag="5")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub request_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub coordinator: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub transmitter: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="8")]
+    pub result_code: u64,
+    #[prost(bytes="vec", tag="9")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="10")]
+    pub evt_context: ::core::option::Option<EventContext>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RouterRequestProcessed {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(bytes="vec", tag="5")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub request_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="6")]
+    pub subscription_id: u64,
+    #[prost(string, tag="7")]
+    pub total_cost_juels: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="8")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub transmitter: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="9")]
+    pub result_code: u64,
+    #[prost(bytes="vec", tag="10")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub response: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="11")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub err: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="12")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub callback_return_data: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="13")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="14")]
+    pub total_cost_juels_bigint: ::core::option::Option<BigInt>,
+    #[prost(message, optional, tag="15")]
+    pub evt_context: ::core::option::Option<EventContext>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RouterRequestStart {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(bytes="vec", tag="5")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub request_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub don_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="7")]
+    pub subscription_id: u64,
+    #[prost(bytes="vec", tag="8")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub subscription_owner: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="9")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub requesting_contract: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="10")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub request_initiator: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="11")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="12")]
+    pub data_version: u64,
+    #[prost(uint64, tag="13")]
+    pub callback_gas_limit: u64,
+    #[prost(string, tag="14")]
+    pub estimated_total_cost_juels: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="15")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="16")]
+    pub estimated_total_cost_juels_bigint: ::core::option::Option<BigInt>,
+    #[prost(message, optional, tag="17")]
+    pub evt_context: ::core::option::Option<EventContext>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RouterRequestTimedOut {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(bytes="vec", tag="5")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub request_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="7")]
+    pub evt_context: ::core::option::Option<EventContext>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RouterSubscriptionCanceled {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(uint64, tag="5")]
+    pub subscription_id: u64,
+    #[prost(bytes="vec", tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub funds_recipient: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="7")]
+    pub funds_amount: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="8")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="9")]
+    pub funds_amount_bigint: ::core::option::Option<BigInt>,
+    #[prost(message, optional, tag="10")]
+    pub evt_context: ::core::option::Option<EventContext>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RouterSubscriptionConsumerAdded {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(uint64, tag="5")]
+    pub subscription_id: u64,
+    #[prost(bytes="vec", tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub consumer: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="8")]
+    pub evt_context: ::core::option::Option<EventContext>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RouterSubscriptionConsumerRemoved {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(uint64, tag="5")]
+    pub subscription_id: u64,
+    #[prost(bytes="vec", tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub consumer: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="8")]
+    pub evt_context: ::core::option::Option<EventContext>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RouterSubscriptionCreated {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(uint64, tag="5")]
+    pub subscription_id: u64,
+    #[prost(bytes="vec", tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub owner: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="8")]
+    pub evt_context: ::core::option::Option<EventContext>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RouterSubscriptionFunded {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(uint64, tag="5")]
+    pub subscription_id: u64,
+    #[prost(string, tag="6")]
+    pub old_balance: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub new_balance: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="8")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="9")]
+    pub old_balance_bigint: ::core::option::Option<BigInt>,
+    #[prost(message, optional, tag="10")]
+    pub new_balance_bigint: ::core::option::Option<BigInt>,
+    #[prost(message, optional, tag="11")]
+    pub evt_context: ::core::option::Option<EventContext>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RouterSubscriptionOwnerTransferRequested {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(uint64, tag="5")]
+    pub subscription_id: u64,
+    #[prost(bytes="vec", tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub from: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub to: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="9")]
+    pub evt_context: ::core::option::Option<EventContext>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RouterSubscriptionOwnerTransferred {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(uint64, tag="5")]
+    pub subscription_id: u64,
+    #[prost(bytes="vec", tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub from: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub to: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="9")]
+    pub evt_context: ::core::option::Option<EventContext>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Calls {
+    #[prost(message, repeated, tag="1")]
+    pub nft_call_approves: ::prost::alloc::vec::Vec<NftApproveCall>,
+    #[prost(message, repeated, tag="2")]
+    pub nft_call_burns: ::prost::alloc::vec::Vec<NftBurnCall>,
+    #[prost(message, repeated, tag="3")]
+    pub nft_call_grant_roles: ::prost::alloc::vec::Vec<NftGrantRoleCall>,
+    #[prost(message, repeated, tag="4")]
+    pub nft_call_renounce_roles: ::prost::alloc::vec::Vec<NftRenounceRoleCall>,
+    #[prost(message, repeated, tag="5")]
+    pub nft_call_revoke_roles: ::prost::alloc::vec::Vec<NftRevokeRoleCall>,
+    #[prost(message, repeated, tag="6")]
+    pub nft_call_safe_mints: ::prost::alloc::vec::Vec<NftSafeMintCall>,
+    #[prost(message, repeated, tag="7")]
+    pub nft_call_safe_transfer_from_1s: ::prost::alloc::vec::Vec<NftSafeTransferFrom1Call>,
+    #[prost(message, repeated, tag="8")]
+    pub nft_call_safe_transfer_from_2s: ::prost::alloc::vec::Vec<NftSafeTransferFrom2Call>,
+    #[prost(message, repeated, tag="9")]
+    pub nft_call_set_approval_for_alls: ::prost::alloc::vec::Vec<NftSetApprovalForAllCall>,
+    #[prost(message, repeated, tag="10")]
+    pub nft_call_transfer_froms: ::prost::alloc::vec::Vec<NftTransferFromCall>,
+    #[prost(message, repeated, tag="11")]
+    pub attester_call_accept_ownerships: ::prost::alloc::vec::Vec<AttesterAcceptOwnershipCall>,
+    #[prost(message, repeated, tag="12")]
+    pub attester_call_handle_oracle_fulfillments: ::prost::alloc::vec::Vec<AttesterHandleOracleFulfillmentCall>,
+    #[prost(message, repeated, tag="13")]
+    pub attester_call_send_requests: ::prost::alloc::vec::Vec<AttesterSendRequestCall>,
+    #[prost(message, repeated, tag="14")]
+    pub attester_call_set_don_ids: ::prost::alloc::vec::Vec<AttesterSetDonIdCall>,
+    #[prost(message, repeated, tag="15")]
+    pub attester_call_set_sources: ::prost::alloc::vec::Vec<AttesterSetSourceCall>,
+    #[prost(message, repeated, tag="16")]
+    pub attester_call_transfer_ownerships: ::prost::alloc::vec::Vec<AttesterTransferOwnershipCall>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NftApproveCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="7")]
+    pub call_value: ::prost::alloc::string::String,
+    #[prost(uint64, tag="8")]
+    pub call_gas_used: u64,
+    #[prost(bytes="vec", tag="9")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub to: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="10")]
+    pub token_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="11")]
+    pub token_id_bigint: ::core::option::Option<BigInt>,
+    #[prost(bytes="vec", tag="12")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NftBurnCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="7")]
+    pub call_value: ::prost::alloc::string::String,
+    #[prost(uint64, tag="8")]
+    pub call_gas_used: u64,
+    #[prost(string, tag="9")]
+    pub token_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="10")]
+    pub token_id_bigint: ::core::option::Option<BigInt>,
+    #[prost(bytes="vec", tag="11")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NftGrantRoleCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="7")]
+    pub call_value: ::prost::alloc::string::String,
+    #[prost(uint64, tag="8")]
+    pub call_gas_used: u64,
+    #[prost(bytes="vec", tag="9")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub role: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="10")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="11")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NftRenounceRoleCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="7")]
+    pub call_value: ::prost::alloc::string::String,
+    #[prost(uint64, tag="8")]
+    pub call_gas_used: u64,
+    #[prost(bytes="vec", tag="9")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub role: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="10")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub caller_confirmation: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="11")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NftRevokeRoleCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="7")]
+    pub call_value: ::prost::alloc::string::String,
+    #[prost(uint64, tag="8")]
+    pub call_gas_used: u64,
+    #[prost(bytes="vec", tag="9")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub role: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="10")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="11")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NftSafeMintCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="7")]
+    pub call_value: ::prost::alloc::string::String,
+    #[prost(uint64, tag="8")]
+    pub call_gas_used: u64,
+    #[prost(bytes="vec", tag="9")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub to: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="10")]
+    pub uri: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="11")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NftSafeTransferFrom1Call {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="7")]
+    pub call_value: ::prost::alloc::string::String,
+    #[prost(uint64, tag="8")]
+    pub call_gas_used: u64,
+    #[prost(bytes="vec", tag="9")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub from: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="10")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub to: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="11")]
+    pub token_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="12")]
+    pub token_id_bigint: ::core::option::Option<BigInt>,
+    #[prost(bytes="vec", tag="13")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NftSafeTransferFrom2Call {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="7")]
+    pub call_value: ::prost::alloc::string::String,
+    #[prost(uint64, tag="8")]
+    pub call_gas_used: u64,
+    #[prost(bytes="vec", tag="9")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub from: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="10")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub to: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="11")]
+    pub token_id: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="12")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="13")]
+    pub token_id_bigint: ::core::option::Option<BigInt>,
+    #[prost(bytes="vec", tag="14")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NftSetApprovalForAllCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="7")]
+    pub call_value: ::prost::alloc::string::String,
+    #[prost(uint64, tag="8")]
+    pub call_gas_used: u64,
+    #[prost(bytes="vec", tag="9")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub operator: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bool, tag="10")]
+    pub approved: bool,
+    #[prost(bytes="vec", tag="11")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NftTransferFromCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="7")]
+    pub call_value: ::prost::alloc::string::String,
+    #[prost(uint64, tag="8")]
+    pub call_gas_used: u64,
+    #[prost(bytes="vec", tag="9")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub from: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="10")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub to: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="11")]
+    pub token_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="12")]
+    pub token_id_bigint: ::core::option::Option<BigInt>,
+    #[prost(bytes="vec", tag="13")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AttesterAcceptOwnershipCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="7")]
+    pub call_value: ::prost::alloc::string::String,
+    #[prost(uint64, tag="8")]
+    pub call_gas_used: u64,
+    #[prost(bytes="vec", tag="9")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AttesterHandleOracleFulfillmentCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="7")]
+    pub call_value: ::prost::alloc::string::String,
+    #[prost(uint64, tag="8")]
+    pub call_gas_used: u64,
+    #[prost(bytes="vec", tag="9")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub request_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="10")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub response: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="11")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub err: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="12")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AttesterSendRequestCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="7")]
+    pub call_value: ::prost::alloc::string::String,
+    #[prost(uint64, tag="8")]
+    pub call_gas_used: u64,
+    #[prost(string, repeated, tag="9")]
+    pub args: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(uint64, tag="10")]
+    pub subscription_id: u64,
+    #[prost(uint64, tag="11")]
+    pub callback_gas_limit: u64,
+    #[prost(bytes="vec", tag="12")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AttesterSetDonIdCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="7")]
+    pub call_value: ::prost::alloc::string::String,
+    #[prost(uint64, tag="8")]
+    pub call_gas_used: u64,
+    #[prost(bytes="vec", tag="9")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub new_don_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="10")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AttesterSetSourceCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="7")]
+    pub call_value: ::prost::alloc::string::String,
+    #[prost(uint64, tag="8")]
+    pub call_gas_used: u64,
+    #[prost(string, tag="9")]
+    pub u_source: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="10")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AttesterTransferOwnershipCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="7")]
+    pub call_value: ::prost::alloc::string::String,
+    #[prost(uint64, tag="8")]
+    pub call_gas_used: u64,
+    #[prost(bytes="vec", tag="9")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub to: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="10")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+}
+/// An unsigned integer wider than 64 bits, such as a token id. `be_bytes` is the big-endian
+/// magnitude; `u64_value` repeats it when it fits so consumers can skip big-integer decoding.
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BigInt {
+    #[prost(bytes="vec", tag="1")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub be_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, optional, tag="2")]
+    pub u64_value: ::core::option::Option<u64>,
+}
+/// Transaction and log context of an event, so consumers need not join against raw chain data.
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EventContext {
+    #[prost(string, tag="1")]
+    pub block_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub tx_index: u32,
+    #[prost(bytes="vec", tag="3")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub tx_from: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub tx_to: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="5")]
+    pub tx_nonce: u64,
+    #[prost(uint64, tag="6")]
+    pub tx_gas_used: u64,
+    /// Price paid per gas unit: the fixed gas price for legacy transactions, base fee plus
+    /// priority fee for EIP-1559 ones.
+    #[prost(string, tag="7")]
+    pub tx_effective_gas_price: ::prost::alloc::string::String,
+    #[prost(uint64, tag="8")]
+    pub log_ordinal: u64,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Mints {
+    #[prost(message, repeated, tag="1")]
+    pub mints: ::prost::alloc::vec::Vec<NftMint>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NftMint {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(string, tag="5")]
+    pub token_id: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub to: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="7")]
+    pub uri: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="8")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub minter: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="9")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="10")]
+    pub token_id_bigint: ::core::option::Option<BigInt>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OracleRequests {
+    #[prost(message, repeated, tag="1")]
+    pub requests: ::prost::alloc::vec::Vec<OracleRequest>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OracleRequest {
+    #[prost(bytes="vec", tag="1")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub request_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(enumeration="oracle_request::Status", tag="2")]
+    #[serde(serialize_with = "crate::serialize::oracle_request_status")]
+    pub status: i32,
+    #[prost(message, optional, tag="3")]
+    pub sent: ::core::option::Option<OracleRequestStep>,
+    #[prost(message, optional, tag="4")]
+    pub responded: ::core::option::Option<OracleRequestStep>,
+    #[prost(message, optional, tag="5")]
+    pub fulfilled: ::core::option::Option<OracleRequestStep>,
+    #[prost(message, optional, tag="6")]
+    pub attested: ::core::option::Option<OracleRequestStep>,
+}
+/// Nested message and enum types in `OracleRequest`.
+pub mod oracle_request {
+    #[derive(serde::Serialize)]
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Status {
+        Pending = 0,
+        Responded = 1,
+        Fulfilled = 2,
+        Errored = 3,
+    }
+    impl Status {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Status::Pending => "PENDING",
+                Status::Responded => "RESPONDED",
+                Status::Fulfilled => "FULFILLED",
+                Status::Errored => "ERRORED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "PENDING" => Some(Self::Pending),
+                "RESPONDED" => Some(Self::Responded),
+                "FULFILLED" => Some(Self::Fulfilled),
+                "ERRORED" => Some(Self::Errored),
+                _ => None,
+            }
+        }
+    }
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OracleRequestStep {
+    #[prost(string, tag="1")]
+    pub tx_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub block_number: u64,
+    #[prost(message, optional, tag="3")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub latency_blocks: u64,
+    #[prost(uint64, tag="5")]
+    pub latency_seconds: u64,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BalanceChanges {
+    #[prost(message, repeated, tag="1")]
+    pub balance_changes: ::prost::alloc::vec::Vec<BalanceChange>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BalanceChange {
+    #[prost(uint64, tag="1")]
+    pub ordinal: u64,
+    #[prost(bytes="vec", tag="2")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub owner: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="3")]
+    pub old_balance: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub new_balance: ::prost::alloc::string::String,
+    #[prost(bool, tag="5")]
+    pub emptied: bool,
+    #[prost(bytes="vec", tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FailedCalls {
+    #[prost(message, repeated, tag="1")]
+    pub failed_calls: ::prost::alloc::vec::Vec<FailedCall>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FailedCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bytes="vec", tag="5")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub call_caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="6")]
+    pub call_gas_used: u64,
+    #[prost(bytes="vec", tag="7")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub tx_from: ::prost::alloc::vec::Vec<u8>,
+    /// Whether the whole transaction failed, rather than only this call being caught.
+    #[prost(bool, tag="8")]
+    pub tx_failed: bool,
+    #[prost(bytes="vec", tag="9")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    /// Name of the ABI function called, empty when the selector is unknown.
+    #[prost(string, tag="10")]
+    pub function: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="11")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub input: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="12")]
+    pub failure_reason: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="13")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub revert_data: ::prost::alloc::vec::Vec<u8>,
+    /// The custom error decoded from `revert_data`, `Error` for a revert message or `Panic` for a
+    /// failed assertion. Empty when the data matches none of them.
+    #[prost(string, tag="14")]
+    pub error_name: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag="15")]
+    pub error_args: ::prost::alloc::vec::Vec<ErrorArgument>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ErrorArgument {
+    #[prost(string, tag="1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub value: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RoleSnapshots {
+    #[prost(message, repeated, tag="1")]
+    pub snapshots: ::prost::alloc::vec::Vec<RoleSnapshot>,
+}
+/// The members of a role after the block's grants and revokes.
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RoleSnapshot {
+    #[prost(bytes="vec", tag="1")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub role: ::prost::alloc::vec::Vec<u8>,
+    /// `DEFAULT_ADMIN_ROLE`, `MINTER_ROLE`, ... Empty when the hash is not a known role.
+    #[prost(string, tag="3")]
+    pub role_name: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="4")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub admin_role: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="5")]
+    pub admin_role_name: ::prost::alloc::string::String,
+    /// In the order they were granted.
+    #[prost(bytes="vec", repeated, tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex_list")]
+    pub members: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(uint64, tag="7")]
+    pub block_number: u64,
+    #[prost(message, optional, tag="8")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// Current owner of an attester deployment and the transfer awaiting `acceptOwnership`, if any.
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AttesterOwnership {
+    #[prost(bytes="vec", tag="1")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub owner: ::prost::alloc::vec::Vec<u8>,
+    /// Empty when no transfer is pending.
+    #[prost(bytes="vec", tag="3")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub pending_owner: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="4")]
+    pub requested_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="5")]
+    pub requested_block_number: u64,
+    #[prost(message, optional, tag="6")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
+    pub requested_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="7")]
+    pub updated_block_number: u64,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AttesterOwnerships {
+    #[prost(message, repeated, tag="1")]
+    pub ownerships: ::prost::alloc::vec::Vec<AttesterOwnership>,
+}
+/// The transfers whose request reached the alert threshold in this block, still pending.
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OwnershipAlerts {
+    #[prost(message, repeated, tag="1")]
+    pub alerts: ::prost::alloc::vec::Vec<OwnershipAlert>,
+}
+/// A transfer requested `blocks_pending` blocks ago that the pending owner has not accepted.
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OwnershipAlert {
+    #[prost(message, optional, tag="1")]
+    pub ownership: ::core::option::Option<AttesterOwnership>,
+    #[prost(uint64, tag="2")]
+    pub blocks_pending: u64,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OperatorApprovals {
+    #[prost(message, repeated, tag="1")]
+    pub approvals: ::prost::alloc::vec::Vec<OperatorApproval>,
+}
+/// The operators an owner has approved for all of their tokens, after the block's changes.
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OperatorApproval {
+    #[prost(bytes="vec", tag="1")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub owner: ::prost::alloc::vec::Vec<u8>,
+    /// In the order they were approved. Empty once every operator is revoked.
+    #[prost(bytes="vec", repeated, tag="3")]
+    #[serde(serialize_with = "crate::serialize::hex_list")]
+    pub operators: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(uint64, tag="4")]
+    pub block_number: u64,
+    #[prost(message, optional, tag="5")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MetadataRefreshes {
+    #[prost(message, repeated, tag="1")]
+    pub refreshes: ::prost::alloc::vec::Vec<MetadataRefresh>,
+    /// `BatchMetadataUpdate` ranges spanning more tokens than the module's cap, left unexpanded.
+    #[prost(message, repeated, tag="2")]
+    pub oversized_ranges: ::prost::alloc::vec::Vec<MetadataRefreshRange>,
+}
+/// A token whose ERC-4906 metadata changed, listed once per block.
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MetadataRefresh {
+    #[prost(bytes="vec", tag="1")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="2")]
+    pub token_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="3")]
+    pub token_id_bigint: ::core::option::Option<BigInt>,
+    /// The first update of the token in the block.
+    #[prost(string, tag="4")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="5")]
+    pub evt_index: u32,
+    #[prost(uint64, tag="6")]
+    pub block_number: u64,
+    #[prost(message, optional, tag="7")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MetadataRefreshRange {
+    #[prost(bytes="vec", tag="1")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="2")]
+    pub from_token_id: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub to_token_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="4")]
+    pub from_token_id_bigint: ::core::option::Option<BigInt>,
+    #[prost(message, optional, tag="5")]
+    pub to_token_id_bigint: ::core::option::Option<BigInt>,
+    #[prost(string, tag="6")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="7")]
+    pub evt_index: u32,
+    #[prost(uint64, tag="8")]
+    pub block_number: u64,
+    #[prost(message, optional, tag="9")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Provenances {
+    #[prost(message, repeated, tag="1")]
+    pub provenances: ::prost::alloc::vec::Vec<Provenance>,
+}
+/// A minted photo and the attestation of the same owner and url it was minted from.
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Provenance {
+    #[prost(bytes="vec", tag="1")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub nft_contract_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="2")]
+    pub token_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="3")]
+    pub token_id_bigint: ::core::option::Option<BigInt>,
+    #[prost(bytes="vec", tag="4")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub owner: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="5")]
+    pub url: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="6")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub attester_contract_address: ::prost::alloc::vec::Vec<u8>,
+    /// The Chainlink Functions request that attested the photo.
+    #[prost(bytes="vec", tag="7")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub request_id: ::prost::alloc::vec::Vec<u8>,
+    /// Identity of the attester, as reported by the request.
+    #[prost(string, tag="8")]
+    pub attester: ::prost::alloc::string::String,
+    #[prost(string, tag="9")]
+    pub mint_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="10")]
+    pub mint_block_number: u64,
+    #[prost(message, optional, tag="11")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
+    pub mint_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(string, tag="12")]
+    pub attestation_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="13")]
+    pub attestation_block_number: u64,
+    #[prost(message, optional, tag="14")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
+    pub attestation_block_time: ::core::option::Option<::prost_types::Timestamp>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AttestationCosts {
+    #[prost(message, repeated, tag="1")]
+    pub costs: ::prost::alloc::vec::Vec<AttestationCost>,
+}
+/// Gas spent on an attestation request so far, in wei. The LINK billed for it is reported by
+/// `map_router_events`.
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AttestationCost {
+    #[prost(bytes="vec", tag="1")]
+    #[serde(serialize_with = "crate::serialize::hex")]
+    pub request_id: ::prost::alloc::vec::Vec<u8>,
+    /// The `sendRequest` transaction.
+    #[prost(message, optional, tag="2")]
+    pub sent: ::core::option::Option<TransactionCost>,
+    /// The DON's fulfilment transaction, unset until the `Response` lands.
+    #[prost(message, optional, tag="3")]
+    pub fulfilled: ::core::option::Option<TransactionCost>,
+    #[prost(string, tag="4")]
+    pub total_cost_wei: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionCost {
+    #[prost(string, tag="1")]
+    pub tx_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub block_number: u64,
+    #[prost(message, optional, tag="3")]
+    #[serde(serialize_with = "crate::serialize::timestamp")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub gas_used: u64,
+    #[prost(string, tag="5")]
+    pub effective_gas_price: ::prost::alloc::string::String,
+    /// This request's share of `gas_used` times `effective_gas_price`: a transaction carrying
+    /// several requests is split evenly between them, the remainder going to the first one.
+    #[prost(string, tag="6")]
+    pub cost_wei: ::prost::alloc::string::String,
+    /// Number of requests of the same step in the transaction.
+    #[prost(uint32, tag="7")]
+    pub requests_in_tx: u32,
 }
 // @@protoc_insertion_point(module)
@@ -0,0 +1,118 @@
+use crate::pb::contract::v1 as contract;
+use substreams::errors::Error;
+use substreams::store::{StoreGet, StoreSet};
+use substreams::Hex;
+
+/// Largest number of blocks between an attestation and the mint of the same photo for the two
+/// to be linked, parsed from the `map_provenance` params.
+pub fn parse_window(params: &str) -> Result<u64, Error> {
+    params.trim().parse::<u64>().map_err(|_| {
+        Error::msg(format!(
+            "invalid params {:?}: expected a number of blocks",
+            params
+        ))
+    })
+}
+
+/// Records the latest attestation of every photo, keyed by its owner and url. Attestations
+/// without a url name no photo and are left out.
+pub fn set_attestations(
+    events: &contract::Events,
+    store: &impl StoreSet<contract::AttesterAttested>,
+) {
+    for evt in events
+        .attester_attesteds
+        .iter()
+        .filter(|evt| !evt.url.is_empty())
+    {
+        store.set(
+            evt.evt_index as u64,
+            attestation_key(&evt.owner, &evt.url),
+            evt,
+        );
+    }
+}
+
+/// Records the latest mint of every photo, keyed by its recipient and token uri. Mints without a
+/// token uri are left out, as they would all share one key per recipient.
+pub fn set_minted_photos(mints: &contract::Mints, store: &impl StoreSet<contract::NftMint>) {
+    for mint in mints.mints.iter().filter(|mint| !mint.uri.is_empty()) {
+        store.set(
+            mint.evt_index as u64,
+            minted_photo_key(&mint.to, &mint.uri),
+            mint,
+        );
+    }
+}
+
+/// Links the mints and attestations of this block to their counterpart, whichever came first,
+/// when they are at most `window` blocks apart. Mints are minted to the attested owner with the
+/// attested url as their token uri.
+pub fn provenances(
+    window: u64,
+    mints: &contract::Mints,
+    events: &contract::Events,
+    attestations: &impl StoreGet<contract::AttesterAttested>,
+    minted_photos: &impl StoreGet<contract::NftMint>,
+) -> contract::Provenances {
+    // The stores already hold this block's changes, so a mint and an attestation of the same block
+    // are found from the mint side and skipped from the attestation side. An empty uri or url names
+    // no photo, so those are never linked.
+    let from_mints = mints.mints.iter().filter_map(|mint| {
+        if mint.uri.is_empty() {
+            return None;
+        }
+        let attestation = attestations.get_last(attestation_key(&mint.to, &mint.uri))?;
+        Some((mint.clone(), attestation))
+    });
+    let from_attestations = events.attester_attesteds.iter().filter_map(|attestation| {
+        if attestation.url.is_empty() {
+            return None;
+        }
+        let mint =
+            minted_photos.get_last(minted_photo_key(&attestation.owner, &attestation.url))?;
+        (mint.evt_block_number != attestation.evt_block_number).then(|| (mint, attestation.clone()))
+    });
+
+    let provenances = from_mints
+        .chain(from_attestations)
+        .filter(|(mint, attestation)| {
+            mint.evt_block_number.abs_diff(attestation.evt_block_number) <= window
+        })
+        .map(|(mint, attestation)| provenance(mint, attestation))
+        .collect();
+
+    contract::Provenances { provenances }
+}
+
+fn provenance(
+    mint: contract::NftMint,
+    attestation: contract::AttesterAttested,
+) -> contract::Provenance {
+    contract::Provenance {
+        nft_contract_address: mint.contract_address,
+        token_id: mint.token_id,
+        token_id_bigint: mint.token_id_bigint,
+        owner: mint.to,
+        url: mint.uri,
+        attester_contract_address: attestation.contract_address,
+        request_id: attestation.request_id,
+        attester: attestation.attester,
+        mint_tx_hash: mint.evt_tx_hash,
+        mint_block_number: mint.evt_block_number,
+        mint_block_time: mint.evt_block_time,
+        attestation_tx_hash: attestation.evt_tx_hash,
+        attestation_block_number: attestation.evt_block_number,
+        attestation_block_time: attestation.evt_block_time,
+    }
+}
+
+// The url is the last segment, so the colons it contains do not get in the way of the
+// owner segment.
+fn attestation_key(owner: &[u8], url: &str) -> String {
+    format!("attestation:{}:{}", Hex(owner), url)
+}
+
+fn minted_photo_key(to: &[u8], uri: &str) -> String {
+    format!("mint:{}:{}", Hex(to), uri)
+}
@@ -0,0 +1,570 @@
+//! Runs the package's modules natively over recorded blocks, with in-memory stores standing in
+//! for the Substreams ones, for the `replay` binary.
+
+use crate::modules;
+use crate::pb::contract::v1 as contract;
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use substreams::errors::Error;
+use substreams::pb::substreams::store_delta::Operation;
+use substreams::pb::substreams::Clock;
+use substreams::scalar::BigInt;
+use substreams::store::{
+    Appender, DeltaBigInt, Deltas, StoreAdd, StoreDelete, StoreGet, StoreNew, StoreSet,
+    StoreSetIfNotExists,
+};
+use substreams::Hex;
+use substreams_ethereum::pb::eth::v2 as eth;
+
+/// The modules `Replay` runs, in the order their outputs are printed. `db_out` and `graph_out`
+/// are left out: their output is only meant for the sinks.
+pub const MODULES: &[&str] = &[
+    "map_events",
+    "map_calls",
+    "map_failures",
+    "map_router_events",
+    "map_mints",
+    "store_attestations",
+    "store_minted_photos",
+    "store_token_owners",
+    "store_balances",
+    "store_token_approvals",
+    "store_operator_approvals",
+    "store_oracle_request_steps",
+    "store_role_members",
+    "store_role_admins",
+    "store_transaction_costs",
+    "store_daily_costs",
+    "store_attester_ownership",
+    "store_transfer_requests",
+    "map_provenance",
+    "map_metadata_refreshes",
+    "map_operator_approvals",
+    "map_oracle_requests",
+    "map_role_snapshots",
+    "map_attestation_costs",
+    "map_attester_ownership",
+    "map_stale_transfers",
+    "map_balance_changes",
+];
+
+// The modules `Replay::new` requires params for.
+const PARAMS: &[&str] = &[
+    "map_events",
+    "map_calls",
+    "map_failures",
+    "map_router_events",
+    "map_provenance",
+    "map_metadata_refreshes",
+    "map_stale_transfers",
+];
+
+/// The modules of the package, run block after block over the same stores.
+pub struct Replay {
+    params: HashMap<String, String>,
+    stores: Stores,
+}
+
+#[derive(Default)]
+struct Stores {
+    attestations: MemoryStore<contract::AttesterAttested>,
+    minted_photos: MemoryStore<contract::NftMint>,
+    token_owners: MemoryStore<String>,
+    balances: MemoryStore<BigInt>,
+    token_approvals: MemoryStore<String>,
+    operator_approvals: MemoryStore<String>,
+    oracle_request_steps: MemoryStore<contract::OracleRequestStep>,
+    role_members: MemoryStore<String>,
+    role_admins: MemoryStore<String>,
+    transaction_costs: MemoryStore<contract::TransactionCost>,
+    daily_costs: MemoryStore<BigInt>,
+    attester_ownership: MemoryStore<contract::AttesterOwnership>,
+    transfer_requests: MemoryStore<String>,
+}
+
+impl Replay {
+    /// Takes the params of every module, keyed by module name as in the `params` section of
+    /// `substreams.yaml`. The modules parse them on every block, as they do under Substreams.
+    pub fn new(params: &HashMap<String, String>) -> Result<Self, Error> {
+        if let Some(module) = PARAMS.iter().find(|module| !params.contains_key(**module)) {
+            return Err(Error::msg(format!("missing params for {}", module)));
+        }
+        Ok(Replay {
+            params: params.clone(),
+            stores: Stores::default(),
+        })
+    }
+
+    /// Runs every module over `blk`, which must follow the previously processed block. Returns
+    /// one JSON line per module that produced something, shaped like the `jsonl` output of
+    /// `substreams run`: map outputs under `@data`, and store changes under `@data.deltas`.
+    pub fn process(&self, blk: &eth::Block) -> Result<Vec<Value>, Error> {
+        let param = |module: &str| self.params[module].as_str();
+        let clock = Clock {
+            id: Hex(&blk.hash).to_string(),
+            number: blk.number,
+            timestamp: blk.header.as_ref().and_then(|header| header.timestamp),
+        };
+        let mut outputs = Outputs {
+            block_number: blk.number,
+            lines: Vec::new(),
+        };
+
+        let events = modules::map_events(param("map_events"), blk)?;
+        outputs.map("map_events", "contract.v1.Events", &events);
+        let calls = modules::map_calls(param("map_calls"), blk)?;
+        outputs.map("map_calls", "contract.v1.Calls", &calls);
+        outputs.map(
+            "map_failures",
+            "contract.v1.FailedCalls",
+            &modules::map_failures(param("map_failures"), blk)?,
+        );
+        let mints = modules::map_mints(&events, &calls)?;
+        outputs.map("map_mints", "contract.v1.Mints", &mints);
+
+        let stores = &self.stores;
+        modules::store_attestations(&events, &stores.attestations);
+        modules::store_minted_photos(&mints, &stores.minted_photos);
+        modules::store_token_owners(&events, &stores.token_owners);
+        modules::store_balances(&events, &stores.balances);
+        modules::store_token_approvals(&events, &stores.token_approvals);
+        modules::store_operator_approvals(&events, &stores.operator_approvals);
+        modules::store_oracle_request_steps(&events, &stores.oracle_request_steps);
+        modules::store_role_members(&events, &stores.role_members);
+        modules::store_role_admins(&events, &stores.role_admins);
+        modules::store_transaction_costs(&events, &stores.transaction_costs);
+        modules::store_daily_costs(&events, &stores.daily_costs);
+        modules::store_attester_ownership(&events, &stores.attester_ownership);
+        modules::store_transfer_requests(&events, &stores.transfer_requests);
+
+        // The stores hold this block's changes, as they do for the modules reading them in
+        // `get` mode.
+        outputs.map(
+            "map_router_events",
+            "contract.v1.Events",
+            &modules::map_router_events(
+                param("map_router_events"),
+                blk,
+                &stores.oracle_request_steps,
+            )?,
+        );
+        outputs.map(
+            "map_provenance",
+            "contract.v1.Provenances",
+            &modules::map_provenance(
+                param("map_provenance"),
+                &mints,
+                &events,
+                &stores.attestations,
+                &stores.minted_photos,
+            )?,
+        );
+        outputs.map(
+            "map_metadata_refreshes",
+            "contract.v1.MetadataRefreshes",
+            &modules::map_metadata_refreshes(param("map_metadata_refreshes"), &events)?,
+        );
+        outputs.map(
+            "map_operator_approvals",
+            "contract.v1.OperatorApprovals",
+            &modules::map_operator_approvals(&events, &stores.operator_approvals)?,
+        );
+        outputs.map(
+            "map_oracle_requests",
+            "contract.v1.OracleRequests",
+            &modules::map_oracle_requests(&events, &stores.oracle_request_steps)?,
+        );
+        outputs.map(
+            "map_role_snapshots",
+            "contract.v1.RoleSnapshots",
+            &modules::map_role_snapshots(&events, &stores.role_members, &stores.role_admins)?,
+        );
+        outputs.map(
+            "map_attestation_costs",
+            "contract.v1.AttestationCosts",
+            &modules::map_attestation_costs(&events, &stores.transaction_costs)?,
+        );
+        outputs.map(
+            "map_attester_ownership",
+            "contract.v1.AttesterOwnerships",
+            &modules::map_attester_ownership(&events, &stores.attester_ownership)?,
+        );
+        outputs.map(
+            "map_stale_transfers",
+            "contract.v1.OwnershipAlerts",
+            &modules::map_stale_transfers(
+                param("map_stale_transfers"),
+                &clock,
+                &stores.attester_ownership,
+                &stores.transfer_requests,
+            )?,
+        );
+
+        let balance_deltas = stores.balances.take_deltas();
+        outputs.map(
+            "map_balance_changes",
+            "contract.v1.BalanceChanges",
+            &modules::map_balance_changes(big_int_deltas(&balance_deltas))?,
+        );
+
+        outputs.store("store_attestations", stores.attestations.take_deltas());
+        outputs.store("store_minted_photos", stores.minted_photos.take_deltas());
+        outputs.store("store_token_owners", stores.token_owners.take_deltas());
+        // Big integers are written as decimal strings, as `substreams run` does.
+        outputs.store(
+            "store_balances",
+            balance_deltas.into_iter().map(decimal).collect(),
+        );
+        outputs.store(
+            "store_token_approvals",
+            stores.token_approvals.take_deltas(),
+        );
+        outputs.store(
+            "store_operator_approvals",
+            stores.operator_approvals.take_deltas(),
+        );
+        outputs.store(
+            "store_oracle_request_steps",
+            stores.oracle_request_steps.take_deltas(),
+        );
+        outputs.store("store_role_members", stores.role_members.take_deltas());
+        outputs.store("store_role_admins", stores.role_admins.take_deltas());
+        outputs.store(
+            "store_transaction_costs",
+            stores.transaction_costs.take_deltas(),
+        );
+        outputs.store(
+            "store_daily_costs",
+            stores
+                .daily_costs
+                .take_deltas()
+                .into_iter()
+                .map(decimal)
+                .collect(),
+        );
+        outputs.store(
+            "store_attester_ownership",
+            stores.attester_ownership.take_deltas(),
+        );
+        outputs.store(
+            "store_transfer_requests",
+            stores.transfer_requests.take_deltas(),
+        );
+
+        outputs
+            .lines
+            .sort_by_key(|(module, _)| MODULES.iter().position(|name| name == module));
+        Ok(outputs.lines.into_iter().map(|(_, line)| line).collect())
+    }
+}
+
+// The JSON lines of one block, with the module that produced each.
+struct Outputs {
+    block_number: u64,
+    lines: Vec<(&'static str, Value)>,
+}
+
+impl Outputs {
+    fn map<T: Serialize + Default + PartialEq>(
+        &mut self,
+        module: &'static str,
+        type_name: &str,
+        output: &T,
+    ) {
+        if output == &T::default() {
+            return;
+        }
+        self.push(module, type_name, json!(output));
+    }
+
+    fn store<V: Serialize>(&mut self, module: &'static str, deltas: Vec<MemoryDelta<V>>) {
+        if deltas.is_empty() {
+            return;
+        }
+        let deltas = deltas
+            .iter()
+            .map(|delta| {
+                json!({
+                    "operation": format!("{:?}", delta.operation).to_uppercase(),
+                    "ordinal": delta.ordinal,
+                    "key": delta.key,
+                    "old_value": delta.old_value,
+                    "new_value": delta.new_value,
+                })
+            })
+            .collect::<Vec<_>>();
+        self.push(module, module, json!({ "deltas": deltas }));
+    }
+
+    fn push(&mut self, module: &'static str, type_name: &str, data: Value) {
+        let line = json!({ "@module": module, "@block": self.block_number, "@type": type_name, "@data": data });
+        self.lines.push((module, line));
+    }
+}
+
+fn decimal(delta: MemoryDelta<BigInt>) -> MemoryDelta<String> {
+    MemoryDelta {
+        operation: delta.operation,
+        ordinal: delta.ordinal,
+        key: delta.key,
+        old_value: delta.old_value.map(|value| value.to_string()),
+        new_value: delta.new_value.map(|value| value.to_string()),
+    }
+}
+
+/// A store kept in memory, standing in for any of the store kinds the modules write to or read
+/// from. It holds the latest value of every key, and the changes made since `take_deltas` was
+/// last called.
+///
+/// Reads at an ordinal see the changes made up to that ordinal in the current block; earlier
+/// blocks only count through their final values.
+pub struct MemoryStore<V> {
+    values: RefCell<BTreeMap<String, V>>,
+    deltas: RefCell<Vec<MemoryDelta<V>>>,
+}
+
+/// One change to a key of a `MemoryStore`.
+pub struct MemoryDelta<V> {
+    pub operation: Operation,
+    pub ordinal: u64,
+    pub key: String,
+    pub old_value: Option<V>,
+    pub new_value: Option<V>,
+}
+
+impl<V> Default for MemoryStore<V> {
+    fn default() -> Self {
+        MemoryStore {
+            values: RefCell::new(BTreeMap::new()),
+            deltas: RefCell::new(Vec::new()),
+        }
+    }
+}
+
+impl<V: Clone> MemoryStore<V> {
+    /// The changes made since the previous call, in the order they were made.
+    pub fn take_deltas(&self) -> Vec<MemoryDelta<V>> {
+        self.deltas.take()
+    }
+
+    fn write(&self, ordinal: u64, key: String, new_value: Option<V>) {
+        let old_value = match &new_value {
+            Some(value) => self.values.borrow_mut().insert(key.clone(), value.clone()),
+            None => self.values.borrow_mut().remove(&key),
+        };
+        let operation = match (&old_value, &new_value) {
+            (_, None) => Operation::Delete,
+            (None, Some(_)) => Operation::Create,
+            (Some(_), Some(_)) => Operation::Update,
+        };
+        self.deltas.borrow_mut().push(MemoryDelta {
+            operation,
+            ordinal,
+            key,
+            old_value,
+            new_value,
+        });
+    }
+
+    fn value_at(&self, ordinal: u64, key: &str) -> Option<V> {
+        let deltas = self.deltas.borrow();
+        let mut changes = deltas.iter().filter(|delta| delta.key == key).peekable();
+        match changes.peek() {
+            Some(first) if first.ordinal > ordinal => first.old_value.clone(),
+            Some(_) => changes
+                .take_while(|delta| delta.ordinal <= ordinal)
+                .last()
+                .and_then(|delta| delta.new_value.clone()),
+            None => self.values.borrow().get(key).cloned(),
+        }
+    }
+
+    fn first_value(&self, key: &str) -> Option<V> {
+        match self.deltas.borrow().iter().find(|delta| delta.key == key) {
+            Some(delta) => delta.old_value.clone(),
+            None => self.values.borrow().get(key).cloned(),
+        }
+    }
+}
+
+impl<V> StoreNew for MemoryStore<V> {
+    fn new() -> Self {
+        MemoryStore::default()
+    }
+}
+
+impl<V: Clone> StoreDelete for MemoryStore<V> {
+    fn delete_prefix(&self, ord: i64, prefix: &String) {
+        let keys = self
+            .values
+            .borrow()
+            .range(prefix.clone()..)
+            .map(|(key, _)| key)
+            .take_while(|key| key.starts_with(prefix.as_str()))
+            .cloned()
+            .collect::<Vec<_>>();
+        for key in keys {
+            self.write(ord as u64, key, None);
+        }
+    }
+}
+
+impl<V: Clone> StoreSet<V> for MemoryStore<V> {
+    fn set<K: AsRef<str>>(&self, ord: u64, key: K, value: &V) {
+        self.write(ord, key.as_ref().to_string(), Some(value.clone()));
+    }
+
+    fn set_many<K: AsRef<str>>(&self, ord: u64, keys: &Vec<K>, value: &V) {
+        for key in keys {
+            self.set(ord, key, value);
+        }
+    }
+}
+
+impl<V: Clone> StoreSetIfNotExists<V> for MemoryStore<V> {
+    fn set_if_not_exists<K: AsRef<str>>(&self, ord: u64, key: K, value: &V) {
+        if !self.values.borrow().contains_key(key.as_ref()) {
+            self.write(ord, key.as_ref().to_string(), Some(value.clone()));
+        }
+    }
+
+    fn set_if_not_exists_many<K: AsRef<str>>(&self, ord: u64, keys: &Vec<K>, value: &V) {
+        for key in keys {
+            self.set_if_not_exists(ord, key, value);
+        }
+    }
+}
+
+impl StoreAdd<BigInt> for MemoryStore<BigInt> {
+    fn add<K: AsRef<str>>(&self, ord: u64, key: K, value: BigInt) {
+        let total = self
+            .values
+            .borrow()
+            .get(key.as_ref())
+            .cloned()
+            .unwrap_or_else(BigInt::zero)
+            + value;
+        self.write(ord, key.as_ref().to_string(), Some(total));
+    }
+
+    fn add_many<K: AsRef<str>>(&self, ord: u64, keys: &Vec<K>, value: BigInt) {
+        for key in keys {
+            self.add(ord, key, value.clone());
+        }
+    }
+}
+
+// Items are stored `;`-terminated, as `StoreAppend` does.
+impl Appender<String> for MemoryStore<String> {
+    fn new() -> Self {
+        MemoryStore::default()
+    }
+
+    fn append<K: AsRef<str>>(&self, ord: u64, key: K, item: String) {
+        let mut items = self
+            .values
+            .borrow()
+            .get(key.as_ref())
+            .cloned()
+            .unwrap_or_default();
+        items.push_str(&item);
+        items.push(';');
+        self.write(ord, key.as_ref().to_string(), Some(items));
+    }
+
+    fn append_all<K: AsRef<str>>(&self, ord: u64, key: K, items: Vec<String>) {
+        for item in items {
+            self.append(ord, &key, item);
+        }
+    }
+}
+
+impl<V: Clone> StoreGet<V> for MemoryStore<V> {
+    fn new(_idx: u32) -> Self {
+        MemoryStore::default()
+    }
+
+    fn get_at<K: AsRef<str>>(&self, ord: u64, key: K) -> Option<V> {
+        self.value_at(ord, key.as_ref())
+    }
+
+    fn get_last<K: AsRef<str>>(&self, key: K) -> Option<V> {
+        self.values.borrow().get(key.as_ref()).cloned()
+    }
+
+    fn get_first<K: AsRef<str>>(&self, key: K) -> Option<V> {
+        self.first_value(key.as_ref())
+    }
+
+    fn has_at<K: AsRef<str>>(&self, ord: u64, key: K) -> bool {
+        self.value_at(ord, key.as_ref()).is_some()
+    }
+
+    fn has_last<K: AsRef<str>>(&self, key: K) -> bool {
+        self.values.borrow().contains_key(key.as_ref())
+    }
+
+    fn has_first<K: AsRef<str>>(&self, key: K) -> bool {
+        self.first_value(key.as_ref()).is_some()
+    }
+}
+
+// Reads an append store as `StoreGetArray` does.
+impl StoreGet<Vec<String>> for MemoryStore<String> {
+    fn new(_idx: u32) -> Self {
+        MemoryStore::default()
+    }
+
+    fn get_at<K: AsRef<str>>(&self, ord: u64, key: K) -> Option<Vec<String>> {
+        self.value_at(ord, key.as_ref()).map(split_items)
+    }
+
+    fn get_last<K: AsRef<str>>(&self, key: K) -> Option<Vec<String>> {
+        self.values
+            .borrow()
+            .get(key.as_ref())
+            .cloned()
+            .map(split_items)
+    }
+
+    fn get_first<K: AsRef<str>>(&self, key: K) -> Option<Vec<String>> {
+        self.first_value(key.as_ref()).map(split_items)
+    }
+
+    fn has_at<K: AsRef<str>>(&self, ord: u64, key: K) -> bool {
+        self.value_at(ord, key.as_ref()).is_some()
+    }
+
+    fn has_last<K: AsRef<str>>(&self, key: K) -> bool {
+        self.values.borrow().contains_key(key.as_ref())
+    }
+
+    fn has_first<K: AsRef<str>>(&self, key: K) -> bool {
+        self.first_value(key.as_ref()).is_some()
+    }
+}
+
+fn split_items(items: String) -> Vec<String> {
+    items
+        .split(';')
+        .filter(|item| !item.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+// What a `Deltas<DeltaBigInt>` input holds, where missing values read as zero.
+fn big_int_deltas(deltas: &[MemoryDelta<BigInt>]) -> Deltas<DeltaBigInt> {
+    let deltas = deltas
+        .iter()
+        .map(|delta| DeltaBigInt {
+            operation: delta.operation,
+            ordinal: delta.ordinal,
+            key: delta.key.clone(),
+            old_value: delta.old_value.clone().unwrap_or_else(BigInt::zero),
+            new_value: delta.new_value.clone().unwrap_or_else(BigInt::zero),
+        })
+        .collect();
+    Deltas { deltas }
+}
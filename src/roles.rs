@@ -0,0 +1,157 @@
+use crate::abi::nft_contract::functions::{DefaultAdminRole, MinterRole};
+use crate::pb::contract::v1 as contract;
+use hex_literal::hex;
+use substreams::store::{Appender, StoreGet, StoreSet};
+use substreams::Hex;
+use substreams_ethereum::Function;
+
+// AccessControl's `DEFAULT_ADMIN_ROLE` is `0x00` and administers every role until
+// `RoleAdminChanged` says otherwise; `MINTER_ROLE` is `keccak256("MINTER_ROLE")`.
+const DEFAULT_ADMIN_ROLE: [u8; 32] = [0u8; 32];
+const MINTER_ROLE: [u8; 32] =
+    hex!("9f2df0fed2c77648de5860a4cc508cd0818c85b8b8a1ab4ceeef8d981c8956a6");
+
+/// Appends every grant (`+<account>`) and revoke (`-<account>`) to the role's member log, in
+/// log order so that a revoke followed by a re-grant in the same block ends up a member.
+pub fn append_member_changes(events: &contract::Events, store: &impl Appender<String>) {
+    let mut changes = events
+        .nft_role_granteds
+        .iter()
+        .map(|evt| {
+            (
+                evt.evt_index,
+                &evt.contract_address,
+                &evt.role,
+                format!("+{}", Hex(&evt.account)),
+            )
+        })
+        .chain(events.nft_role_revokeds.iter().map(|evt| {
+            (
+                evt.evt_index,
+                &evt.contract_address,
+                &evt.role,
+                format!("-{}", Hex(&evt.account)),
+            )
+        }))
+        .collect::<Vec<_>>();
+    changes.sort_by_key(|(evt_index, ..)| *evt_index);
+
+    for (evt_index, contract, role, change) in changes {
+        store.append(evt_index as u64, role_members_key(contract, role), change);
+    }
+}
+
+pub fn set_role_admins(events: &contract::Events, store: &impl StoreSet<String>) {
+    for evt in &events.nft_role_admin_changeds {
+        store.set(
+            evt.evt_index as u64,
+            role_admin_key(&evt.contract_address, &evt.role),
+            &Hex(&evt.new_admin_role).to_string(),
+        );
+    }
+}
+
+/// The full membership of every role touched in this block, after the block's changes.
+pub fn role_snapshots(
+    events: &contract::Events,
+    members: &impl StoreGet<Vec<String>>,
+    admins: &impl StoreGet<String>,
+) -> contract::RoleSnapshots {
+    let mut touched = events
+        .nft_role_granteds
+        .iter()
+        .map(|evt| {
+            (
+                evt.evt_index,
+                &evt.contract_address,
+                &evt.role,
+                evt.evt_block_number,
+                &evt.evt_block_time,
+            )
+        })
+        .chain(events.nft_role_revokeds.iter().map(|evt| {
+            (
+                evt.evt_index,
+                &evt.contract_address,
+                &evt.role,
+                evt.evt_block_number,
+                &evt.evt_block_time,
+            )
+        }))
+        .chain(events.nft_role_admin_changeds.iter().map(|evt| {
+            (
+                evt.evt_index,
+                &evt.contract_address,
+                &evt.role,
+                evt.evt_block_number,
+                &evt.evt_block_time,
+            )
+        }))
+        .collect::<Vec<_>>();
+    touched.sort_by_key(|(evt_index, ..)| *evt_index);
+
+    let mut snapshots: Vec<contract::RoleSnapshot> = Vec::new();
+    for (_, contract, role, block_number, block_time) in touched {
+        if snapshots
+            .iter()
+            .any(|snapshot| &snapshot.contract_address == contract && &snapshot.role == role)
+        {
+            continue;
+        }
+
+        let admin_role = admins
+            .get_last(role_admin_key(contract, role))
+            .and_then(|admin_role| Hex::decode(admin_role).ok())
+            .unwrap_or_else(|| DEFAULT_ADMIN_ROLE.to_vec());
+        snapshots.push(contract::RoleSnapshot {
+            contract_address: contract.clone(),
+            role_name: role_name(role),
+            admin_role_name: role_name(&admin_role),
+            role: role.clone(),
+            admin_role,
+            members: current_members(
+                members
+                    .get_last(role_members_key(contract, role))
+                    .unwrap_or_default(),
+            ),
+            block_number,
+            block_time: *block_time,
+        });
+    }
+
+    contract::RoleSnapshots { snapshots }
+}
+
+/// Name of the roles declared by the NFT contract, empty for unknown hashes.
+fn role_name(role: &[u8]) -> String {
+    if role == DEFAULT_ADMIN_ROLE {
+        DefaultAdminRole::NAME.to_string()
+    } else if role == MINTER_ROLE {
+        MinterRole::NAME.to_string()
+    } else {
+        String::new()
+    }
+}
+
+// Replays the member log, keeping members in the order they were first granted.
+pub(crate) fn current_members(changes: Vec<String>) -> Vec<Vec<u8>> {
+    let mut members: Vec<Vec<u8>> = Vec::new();
+    for change in changes {
+        if let Some(Ok(account)) = change.strip_prefix('+').map(Hex::decode) {
+            if !members.contains(&account) {
+                members.push(account);
+            }
+        } else if let Some(Ok(account)) = change.strip_prefix('-').map(Hex::decode) {
+            members.retain(|member| member != &account);
+        }
+    }
+    members
+}
+
+fn role_members_key(contract_address: &[u8], role: &[u8]) -> String {
+    format!("role:{}:{}:members", Hex(contract_address), Hex(role))
+}
+
+fn role_admin_key(contract_address: &[u8], role: &[u8]) -> String {
+    format!("role:{}:{}:admin", Hex(contract_address), Hex(role))
+}
@@ -0,0 +1,99 @@
+use crate::mapping;
+use crate::oracle;
+use crate::params::RouterSubscription;
+use crate::pb::contract::v1 as contract;
+use substreams::store::StoreGet;
+use substreams_ethereum::pb::eth::v2 as eth;
+
+/// Decodes the Functions router events of `subscription`, with the router ABI only: the router's
+/// own `OwnershipTransfer*` logs share their signature with the attester ones and are left out.
+/// The router serves every subscription on the chain, so the events are kept only when they
+/// carry our subscription id. `RequestNotProcessed` and `RequestTimedOut` carry no subscription
+/// id, so they are kept when the attester sent the request, as recorded in `request_steps`.
+/// Without a subscription id, `RequestStart` and `RequestProcessed` are kept the same way and the
+/// subscription events are dropped. The `Events` lists of the NFT and attester stay empty.
+pub fn router_events(
+    blk: &eth::Block,
+    subscription: &RouterSubscription,
+    request_steps: &impl StoreGet<contract::OracleRequestStep>,
+) -> contract::Events {
+    let mut events = contract::Events::default();
+    for view in blk.receipts() {
+        for log in view
+            .receipt
+            .logs
+            .iter()
+            .filter(|log| log.address == subscription.router)
+        {
+            mapping::map_router_event(blk, view.transaction, log, &mut events);
+        }
+    }
+
+    let Some(id) = subscription.subscription_id else {
+        return sent_request_events(events, request_steps);
+    };
+    events
+        .router_request_starts
+        .retain(|evt| evt.subscription_id == id);
+    events
+        .router_request_processeds
+        .retain(|evt| evt.subscription_id == id);
+    events
+        .router_request_not_processeds
+        .retain(|evt| oracle::was_sent(&evt.request_id, request_steps));
+    events
+        .router_request_timed_outs
+        .retain(|evt| oracle::was_sent(&evt.request_id, request_steps));
+    events
+        .router_subscription_createds
+        .retain(|evt| evt.subscription_id == id);
+    events
+        .router_subscription_fundeds
+        .retain(|evt| evt.subscription_id == id);
+    events
+        .router_subscription_canceleds
+        .retain(|evt| evt.subscription_id == id);
+    events
+        .router_subscription_consumer_addeds
+        .retain(|evt| evt.subscription_id == id);
+    events
+        .router_subscription_consumer_removeds
+        .retain(|evt| evt.subscription_id == id);
+    events
+        .router_subscription_owner_transfer_requesteds
+        .retain(|evt| evt.subscription_id == id);
+    events
+        .router_subscription_owner_transferreds
+        .retain(|evt| evt.subscription_id == id);
+    events
+}
+
+fn sent_request_events(
+    events: contract::Events,
+    request_steps: &impl StoreGet<contract::OracleRequestStep>,
+) -> contract::Events {
+    let sent = |request_id: &[u8]| oracle::was_sent(request_id, request_steps);
+    contract::Events {
+        router_request_starts: events
+            .router_request_starts
+            .into_iter()
+            .filter(|evt| sent(&evt.request_id))
+            .collect(),
+        router_request_processeds: events
+            .router_request_processeds
+            .into_iter()
+            .filter(|evt| sent(&evt.request_id))
+            .collect(),
+        router_request_not_processeds: events
+            .router_request_not_processeds
+            .into_iter()
+            .filter(|evt| sent(&evt.request_id))
+            .collect(),
+        router_request_timed_outs: events
+            .router_request_timed_outs
+            .into_iter()
+            .filter(|evt| sent(&evt.request_id))
+            .collect(),
+        ..Default::default()
+    }
+}
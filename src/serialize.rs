@@ -0,0 +1,48 @@
+//! `serialize_with` helpers for the serde derives of `pb::contract::v1`, set up in
+//! `buf.gen.yaml`. As in the protobuf JSON mapping, timestamps are RFC 3339 UTC date-times and
+//! enums are written by name; bytes are hex without the `0x` prefix, like the transaction hashes.
+
+use crate::costs;
+use crate::pb::contract::v1::oracle_request::Status;
+use prost_types::Timestamp;
+use serde::ser::{SerializeSeq, Serializer};
+use substreams::Hex;
+
+pub fn hex<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(&Hex(bytes))
+}
+
+pub fn hex_list<S: Serializer>(items: &[Vec<u8>], serializer: S) -> Result<S::Ok, S::Error> {
+    let mut seq = serializer.serialize_seq(Some(items.len()))?;
+    for bytes in items {
+        seq.serialize_element(&Hex(bytes).to_string())?;
+    }
+    seq.end()
+}
+
+pub fn timestamp<S: Serializer>(
+    time: &Option<Timestamp>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let Some(time) = time else {
+        return serializer.serialize_none();
+    };
+    let seconds = time.seconds.rem_euclid(86_400);
+    serializer.collect_str(&format_args!(
+        "{}T{:02}:{:02}:{:02}Z",
+        costs::utc_day(&Some(*time)),
+        seconds / 3_600,
+        seconds / 60 % 60,
+        seconds % 60
+    ))
+}
+
+pub fn oracle_request_status<S: Serializer>(
+    status: &i32,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match Status::try_from(*status) {
+        Ok(status) => serializer.serialize_str(status.as_str_name()),
+        Err(_) => serializer.serialize_i32(*status),
+    }
+}
@@ -0,0 +1,591 @@
+//! Synthetic blocks for the offline tests. Logs are encoded from the `Abigen` event types, so
+//! a test states the event it expects and the block that carries it at once.
+
+#![allow(dead_code)]
+
+use ethabi::{long_signature, ParamType, Token};
+use miracam::abi::{attester_contract, nft_contract, router_contract};
+use substreams::scalar::BigInt;
+use substreams_ethereum::pb::eth::v2 as eth;
+
+pub const NFT: [u8; 20] = [0x11; 20];
+pub const ATTESTER: [u8; 20] = [0x22; 20];
+pub const ROUTER: [u8; 20] = [0x33; 20];
+pub const UNTRACKED: [u8; 20] = [0x44; 20];
+
+pub const BLOCK_NUMBER: u64 = 17968303;
+pub const BLOCK_TIMESTAMP: i64 = 1_700_000_000;
+
+/// An ABI event encoded back into the log that emits it.
+pub trait IntoLog {
+    fn to_log(&self, address: &[u8; 20]) -> eth::Log;
+}
+
+/// One input of an event: its type, value and whether it is indexed.
+struct Input(ParamType, Token, bool);
+
+fn encode_log(address: &[u8; 20], name: &str, inputs: Vec<Input>) -> eth::Log {
+    let kinds = inputs
+        .iter()
+        .map(|Input(kind, ..)| kind.clone())
+        .collect::<Vec<_>>();
+    let mut topics = vec![long_signature(name, &kinds).as_bytes().to_vec()];
+    let mut data = Vec::new();
+    for Input(_, token, indexed) in inputs {
+        if indexed {
+            topics.push(ethabi::encode(&[token]));
+        } else {
+            data.push(token);
+        }
+    }
+
+    eth::Log {
+        address: address.to_vec(),
+        topics,
+        data: ethabi::encode(&data),
+        ..Default::default()
+    }
+}
+
+fn address(value: &[u8]) -> Input {
+    Input(
+        ParamType::Address,
+        Token::Address(ethabi::Address::from_slice(value)),
+        true,
+    )
+}
+
+fn bytes32(value: &[u8; 32]) -> Input {
+    Input(
+        ParamType::FixedBytes(32),
+        Token::FixedBytes(value.to_vec()),
+        true,
+    )
+}
+
+fn uint(bits: usize, value: &BigInt) -> Input {
+    let (_, be_bytes) = value.to_bytes_be();
+    Input(
+        ParamType::Uint(bits),
+        Token::Uint(ethabi::Uint::from_big_endian(&be_bytes)),
+        true,
+    )
+}
+
+fn bytes(value: &[u8]) -> Input {
+    Input(ParamType::Bytes, Token::Bytes(value.to_vec()), false)
+}
+
+fn string(value: &str) -> Input {
+    Input(ParamType::String, Token::String(value.to_string()), false)
+}
+
+fn bool(value: bool) -> Input {
+    Input(ParamType::Bool, Token::Bool(value), false)
+}
+
+fn data(Input(kind, token, _): Input) -> Input {
+    Input(kind, token, false)
+}
+
+impl IntoLog for nft_contract::events::Approval {
+    fn to_log(&self, contract: &[u8; 20]) -> eth::Log {
+        let inputs = vec![
+            address(&self.owner),
+            address(&self.approved),
+            uint(256, &self.token_id),
+        ];
+        encode_log(contract, "Approval", inputs)
+    }
+}
+
+impl IntoLog for nft_contract::events::ApprovalForAll {
+    fn to_log(&self, contract: &[u8; 20]) -> eth::Log {
+        let inputs = vec![
+            address(&self.owner),
+            address(&self.operator),
+            bool(self.approved),
+        ];
+        encode_log(contract, "ApprovalForAll", inputs)
+    }
+}
+
+impl IntoLog for nft_contract::events::BatchMetadataUpdate {
+    fn to_log(&self, contract: &[u8; 20]) -> eth::Log {
+        let inputs = vec![
+            data(uint(256, &self.u_from_token_id)),
+            data(uint(256, &self.u_to_token_id)),
+        ];
+        encode_log(contract, "BatchMetadataUpdate", inputs)
+    }
+}
+
+impl IntoLog for nft_contract::events::MetadataUpdate {
+    fn to_log(&self, contract: &[u8; 20]) -> eth::Log {
+        encode_log(
+            contract,
+            "MetadataUpdate",
+            vec![data(uint(256, &self.u_token_id))],
+        )
+    }
+}
+
+impl IntoLog for nft_contract::events::RoleAdminChanged {
+    fn to_log(&self, contract: &[u8; 20]) -> eth::Log {
+        let inputs = vec![
+            bytes32(&self.role),
+            bytes32(&self.previous_admin_role),
+            bytes32(&self.new_admin_role),
+        ];
+        encode_log(contract, "RoleAdminChanged", inputs)
+    }
+}
+
+impl IntoLog for nft_contract::events::RoleGranted {
+    fn to_log(&self, contract: &[u8; 20]) -> eth::Log {
+        let inputs = vec![
+            bytes32(&self.role),
+            address(&self.account),
+            address(&self.sender),
+        ];
+        encode_log(contract, "RoleGranted", inputs)
+    }
+}
+
+impl IntoLog for nft_contract::events::RoleRevoked {
+    fn to_log(&self, contract: &[u8; 20]) -> eth::Log {
+        let inputs = vec![
+            bytes32(&self.role),
+            address(&self.account),
+            address(&self.sender),
+        ];
+        encode_log(contract, "RoleRevoked", inputs)
+    }
+}
+
+impl IntoLog for nft_contract::events::Transfer {
+    fn to_log(&self, contract: &[u8; 20]) -> eth::Log {
+        let inputs = vec![
+            address(&self.from),
+            address(&self.to),
+            uint(256, &self.token_id),
+        ];
+        encode_log(contract, "Transfer", inputs)
+    }
+}
+
+impl IntoLog for attester_contract::events::Attested {
+    fn to_log(&self, contract: &[u8; 20]) -> eth::Log {
+        let inputs = vec![
+            bytes32(&self.request_id),
+            address(&self.owner),
+            string(&self.attester),
+            string(&self.url),
+        ];
+        encode_log(contract, "Attested", inputs)
+    }
+}
+
+impl IntoLog for attester_contract::events::OwnershipTransferRequested {
+    fn to_log(&self, contract: &[u8; 20]) -> eth::Log {
+        encode_log(
+            contract,
+            "OwnershipTransferRequested",
+            vec![address(&self.from), address(&self.to)],
+        )
+    }
+}
+
+impl IntoLog for attester_contract::events::OwnershipTransferred {
+    fn to_log(&self, contract: &[u8; 20]) -> eth::Log {
+        encode_log(
+            contract,
+            "OwnershipTransferred",
+            vec![address(&self.from), address(&self.to)],
+        )
+    }
+}
+
+impl IntoLog for attester_contract::events::RequestFulfilled {
+    fn to_log(&self, contract: &[u8; 20]) -> eth::Log {
+        encode_log(contract, "RequestFulfilled", vec![bytes32(&self.id)])
+    }
+}
+
+impl IntoLog for attester_contract::events::RequestSent {
+    fn to_log(&self, contract: &[u8; 20]) -> eth::Log {
+        encode_log(contract, "RequestSent", vec![bytes32(&self.id)])
+    }
+}
+
+impl IntoLog for attester_contract::events::Response {
+    fn to_log(&self, contract: &[u8; 20]) -> eth::Log {
+        let inputs = vec![
+            bytes32(&self.request_id),
+            bytes(&self.response),
+            bytes(&self.err),
+        ];
+        encode_log(contract, "Response", inputs)
+    }
+}
+
+impl IntoLog for router_contract::events::RequestNotProcessed {
+    fn to_log(&self, contract: &[u8; 20]) -> eth::Log {
+        let inputs = vec![
+            bytes32(&self.request_id),
+            data(address(&self.coordinator)),
+            data(address(&self.transmitter)),
+            data(uint(8, &self.result_code)),
+        ];
+        encode_log(contract, "RequestNotProcessed", inputs)
+    }
+}
+
+impl IntoLog for router_contract::events::RequestProcessed {
+    fn to_log(&self, contract: &[u8; 20]) -> eth::Log {
+        let inputs = vec![
+            bytes32(&self.request_id),
+            uint(64, &self.subscription_id),
+            data(uint(96, &self.total_cost_juels)),
+            data(address(&self.transmitter)),
+            data(uint(8, &self.result_code)),
+            bytes(&self.response),
+            bytes(&self.err),
+            bytes(&self.callback_return_data),
+        ];
+        encode_log(contract, "RequestProcessed", inputs)
+    }
+}
+
+impl IntoLog for router_contract::events::RequestStart {
+    fn to_log(&self, contract: &[u8; 20]) -> eth::Log {
+        let inputs = vec![
+            bytes32(&self.request_id),
+            bytes32(&self.don_id),
+            uint(64, &self.subscription_id),
+            data(address(&self.subscription_owner)),
+            data(address(&self.requesting_contract)),
+            data(address(&self.request_initiator)),
+            bytes(&self.data),
+            data(uint(16, &self.data_version)),
+            data(uint(32, &self.callback_gas_limit)),
+            data(uint(96, &self.estimated_total_cost_juels)),
+        ];
+        encode_log(contract, "RequestStart", inputs)
+    }
+}
+
+impl IntoLog for router_contract::events::RequestTimedOut {
+    fn to_log(&self, contract: &[u8; 20]) -> eth::Log {
+        encode_log(contract, "RequestTimedOut", vec![bytes32(&self.request_id)])
+    }
+}
+
+impl IntoLog for router_contract::events::SubscriptionCanceled {
+    fn to_log(&self, contract: &[u8; 20]) -> eth::Log {
+        let inputs = vec![
+            uint(64, &self.subscription_id),
+            data(address(&self.funds_recipient)),
+            data(uint(256, &self.funds_amount)),
+        ];
+        encode_log(contract, "SubscriptionCanceled", inputs)
+    }
+}
+
+impl IntoLog for router_contract::events::SubscriptionConsumerAdded {
+    fn to_log(&self, contract: &[u8; 20]) -> eth::Log {
+        let inputs = vec![
+            uint(64, &self.subscription_id),
+            data(address(&self.consumer)),
+        ];
+        encode_log(contract, "SubscriptionConsumerAdded", inputs)
+    }
+}
+
+impl IntoLog for router_contract::events::SubscriptionConsumerRemoved {
+    fn to_log(&self, contract: &[u8; 20]) -> eth::Log {
+        let inputs = vec![
+            uint(64, &self.subscription_id),
+            data(address(&self.consumer)),
+        ];
+        encode_log(contract, "SubscriptionConsumerRemoved", inputs)
+    }
+}
+
+impl IntoLog for router_contract::events::SubscriptionCreated {
+    fn to_log(&self, contract: &[u8; 20]) -> eth::Log {
+        let inputs = vec![uint(64, &self.subscription_id), data(address(&self.owner))];
+        encode_log(contract, "SubscriptionCreated", inputs)
+    }
+}
+
+impl IntoLog for router_contract::events::SubscriptionFunded {
+    fn to_log(&self, contract: &[u8; 20]) -> eth::Log {
+        let inputs = vec![
+            uint(64, &self.subscription_id),
+            data(uint(256, &self.old_balance)),
+            data(uint(256, &self.new_balance)),
+        ];
+        encode_log(contract, "SubscriptionFunded", inputs)
+    }
+}
+
+impl IntoLog for router_contract::events::SubscriptionOwnerTransferRequested {
+    fn to_log(&self, contract: &[u8; 20]) -> eth::Log {
+        let inputs = vec![
+            uint(64, &self.subscription_id),
+            data(address(&self.from)),
+            data(address(&self.to)),
+        ];
+        encode_log(contract, "SubscriptionOwnerTransferRequested", inputs)
+    }
+}
+
+impl IntoLog for router_contract::events::SubscriptionOwnerTransferred {
+    fn to_log(&self, contract: &[u8; 20]) -> eth::Log {
+        let inputs = vec![
+            uint(64, &self.subscription_id),
+            data(address(&self.from)),
+            data(address(&self.to)),
+        ];
+        encode_log(contract, "SubscriptionOwnerTransferred", inputs)
+    }
+}
+
+/// A successful transaction carrying `logs`, with its hash and sender derived from `index`.
+/// A successful call to `contract` with the ABI-encoded `input`.
+pub fn call(contract: &[u8; 20], input: Vec<u8>) -> eth::Call {
+    eth::Call {
+        address: contract.to_vec(),
+        caller: vec![0xaa; 20],
+        input,
+        ..Default::default()
+    }
+}
+
+pub fn transaction(index: u32, logs: Vec<eth::Log>) -> eth::TransactionTrace {
+    eth::TransactionTrace {
+        hash: vec![index as u8 + 1; 32],
+        index,
+        from: vec![0xaa; 20],
+        to: vec![0xbb; 20],
+        nonce: 7,
+        gas_used: 21_000,
+        gas_price: Some(eth::BigInt {
+            bytes: BigInt::from(2_000_000_000u64).to_bytes_be().1,
+        }),
+        status: eth::TransactionTraceStatus::Succeeded as i32,
+        receipt: Some(eth::TransactionReceipt {
+            logs,
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+/// A block holding `transactions`, with block-wide log indexes and ordinals assigned in order.
+pub fn block(mut transactions: Vec<eth::TransactionTrace>) -> eth::Block {
+    let mut block_index = 0;
+    for trx in transactions.iter_mut() {
+        for log in trx
+            .receipt
+            .as_mut()
+            .map(|receipt| receipt.logs.iter_mut())
+            .into_iter()
+            .flatten()
+        {
+            log.block_index = block_index;
+            log.ordinal = 100 + block_index as u64;
+            block_index += 1;
+        }
+    }
+
+    eth::Block {
+        number: BLOCK_NUMBER,
+        hash: vec![0xcc; 32],
+        header: Some(eth::BlockHeader {
+            timestamp: Some(prost_types::Timestamp {
+                seconds: BLOCK_TIMESTAMP,
+                nanos: 0,
+            }),
+            ..Default::default()
+        }),
+        transaction_traces: transactions,
+        ..Default::default()
+    }
+}
+
+pub fn request_id(byte: u8) -> [u8; 32] {
+    [byte; 32]
+}
+
+/// One of every NFT event, in a plausible order: a mint, its approvals, metadata updates and
+/// role changes.
+pub fn nft_logs() -> Vec<eth::Log> {
+    let role = [0x9f; 32];
+    vec![
+        nft_contract::events::Transfer {
+            from: vec![0; 20],
+            to: vec![0x01; 20],
+            token_id: BigInt::from(42),
+        }
+        .to_log(&NFT),
+        nft_contract::events::Approval {
+            owner: vec![0x01; 20],
+            approved: vec![0x02; 20],
+            token_id: BigInt::from(42),
+        }
+        .to_log(&NFT),
+        nft_contract::events::ApprovalForAll {
+            owner: vec![0x01; 20],
+            operator: vec![0x03; 20],
+            approved: true,
+        }
+        .to_log(&NFT),
+        nft_contract::events::MetadataUpdate {
+            u_token_id: BigInt::from(42),
+        }
+        .to_log(&NFT),
+        nft_contract::events::BatchMetadataUpdate {
+            u_from_token_id: BigInt::from(1),
+            u_to_token_id: BigInt::from(3),
+        }
+        .to_log(&NFT),
+        nft_contract::events::RoleAdminChanged {
+            role,
+            previous_admin_role: [0; 32],
+            new_admin_role: [0x01; 32],
+        }
+        .to_log(&NFT),
+        nft_contract::events::RoleGranted {
+            role,
+            account: vec![0x04; 20],
+            sender: vec![0x01; 20],
+        }
+        .to_log(&NFT),
+        nft_contract::events::RoleRevoked {
+            role,
+            account: vec![0x05; 20],
+            sender: vec![0x01; 20],
+        }
+        .to_log(&NFT),
+    ]
+}
+
+/// One of every attester event, following a request through its lifecycle.
+pub fn attester_logs() -> Vec<eth::Log> {
+    vec![
+        attester_contract::events::OwnershipTransferRequested {
+            from: vec![0x01; 20],
+            to: vec![0x06; 20],
+        }
+        .to_log(&ATTESTER),
+        attester_contract::events::OwnershipTransferred {
+            from: vec![0x01; 20],
+            to: vec![0x06; 20],
+        }
+        .to_log(&ATTESTER),
+        attester_contract::events::RequestSent {
+            id: request_id(0x0a),
+        }
+        .to_log(&ATTESTER),
+        attester_contract::events::Response {
+            request_id: request_id(0x0a),
+            response: ethabi::encode(&[Token::String("verified".to_string())]),
+            err: vec![],
+        }
+        .to_log(&ATTESTER),
+        attester_contract::events::RequestFulfilled {
+            id: request_id(0x0a),
+        }
+        .to_log(&ATTESTER),
+        attester_contract::events::Attested {
+            request_id: request_id(0x0a),
+            owner: vec![0x01; 20],
+            attester: "miracam-camera-01".to_string(),
+            url: "ipfs://bafyphoto".to_string(),
+        }
+        .to_log(&ATTESTER),
+    ]
+}
+
+/// One of every router event, for subscription `subscription_id`.
+pub fn router_logs(subscription_id: u64) -> Vec<eth::Log> {
+    let subscription_id = BigInt::from(subscription_id);
+    vec![
+        router_contract::events::SubscriptionCreated {
+            subscription_id: subscription_id.clone(),
+            owner: vec![0x01; 20],
+        }
+        .to_log(&ROUTER),
+        router_contract::events::SubscriptionFunded {
+            subscription_id: subscription_id.clone(),
+            old_balance: BigInt::from(0),
+            new_balance: BigInt::from(5_000_000_000_000_000_000u64),
+        }
+        .to_log(&ROUTER),
+        router_contract::events::SubscriptionConsumerAdded {
+            subscription_id: subscription_id.clone(),
+            consumer: ATTESTER.to_vec(),
+        }
+        .to_log(&ROUTER),
+        router_contract::events::RequestStart {
+            request_id: request_id(0x0a),
+            don_id: [0x0d; 32],
+            subscription_id: subscription_id.clone(),
+            subscription_owner: vec![0x01; 20],
+            requesting_contract: ATTESTER.to_vec(),
+            request_initiator: vec![0x01; 20],
+            data: vec![0xde, 0xad],
+            data_version: BigInt::from(1),
+            callback_gas_limit: BigInt::from(300_000),
+            estimated_total_cost_juels: BigInt::from(200_000_000_000_000_000u64),
+        }
+        .to_log(&ROUTER),
+        router_contract::events::RequestProcessed {
+            request_id: request_id(0x0a),
+            subscription_id: subscription_id.clone(),
+            total_cost_juels: BigInt::from(150_000_000_000_000_000u64),
+            transmitter: vec![0x07; 20],
+            result_code: BigInt::from(0),
+            response: vec![0xbe, 0xef],
+            err: vec![],
+            callback_return_data: vec![],
+        }
+        .to_log(&ROUTER),
+        router_contract::events::RequestNotProcessed {
+            request_id: request_id(0x0b),
+            coordinator: vec![0x08; 20],
+            transmitter: vec![0x07; 20],
+            result_code: BigInt::from(4),
+        }
+        .to_log(&ROUTER),
+        router_contract::events::RequestTimedOut {
+            request_id: request_id(0x0c),
+        }
+        .to_log(&ROUTER),
+        router_contract::events::SubscriptionConsumerRemoved {
+            subscription_id: subscription_id.clone(),
+            consumer: ATTESTER.to_vec(),
+        }
+        .to_log(&ROUTER),
+        router_contract::events::SubscriptionOwnerTransferRequested {
+            subscription_id: subscription_id.clone(),
+            from: vec![0x01; 20],
+            to: vec![0x06; 20],
+        }
+        .to_log(&ROUTER),
+        router_contract::events::SubscriptionOwnerTransferred {
+            subscription_id: subscription_id.clone(),
+            from: vec![0x01; 20],
+            to: vec![0x06; 20],
+        }
+        .to_log(&ROUTER),
+        router_contract::events::SubscriptionCanceled {
+            subscription_id,
+            funds_recipient: vec![0x01; 20],
+            funds_amount: BigInt::from(1_000),
+        }
+        .to_log(&ROUTER),
+    ]
+}
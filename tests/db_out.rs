@@ -0,0 +1,99 @@
+mod common;
+
+use common::*;
+use miracam::abi::nft_contract;
+use miracam::modules;
+use miracam::params::TrackedContracts;
+use std::collections::{BTreeMap, HashMap};
+use substreams::scalar::BigInt;
+use substreams::Hex;
+use substreams_database_change::pb::database::table_change::{Operation, PrimaryKey};
+use substreams_database_change::pb::database::DatabaseChanges;
+use substreams_ethereum::pb::eth::v2 as eth;
+
+type Rows = BTreeMap<Vec<(String, String)>, HashMap<String, String>>;
+
+fn transfer(from: [u8; 20], to: [u8; 20]) -> eth::Log {
+    nft_contract::events::Transfer {
+        from: from.to_vec(),
+        to: to.to_vec(),
+        token_id: BigInt::from(42),
+    }
+    .to_log(&NFT)
+}
+
+fn db_out(blk: &eth::Block) -> DatabaseChanges {
+    let contracts =
+        TrackedContracts::parse(&format!("evt_addr:0x{}", Hex(NFT)), "evt_addr").unwrap();
+    modules::db_out(&miracam::map_tracked_events(blk, &contracts)).unwrap()
+}
+
+/// Applies the `tokens` changes to `rows` the way the Postgres sink does, failing where it
+/// would hit a primary key conflict or a missing row.
+fn apply_tokens(rows: &mut Rows, changes: DatabaseChanges) {
+    for change in changes.table_changes {
+        if change.table != "tokens" {
+            continue;
+        }
+        let Some(PrimaryKey::CompositePk(pk)) = change.primary_key else {
+            panic!("tokens has a composite primary key");
+        };
+        let key = pk.keys.into_iter().collect::<BTreeMap<_, _>>();
+        let key = key.into_iter().collect::<Vec<_>>();
+        let fields = change.fields.into_iter().map(|f| (f.name, f.new_value));
+        match Operation::try_from(change.operation).unwrap() {
+            Operation::Create => {
+                assert!(!rows.contains_key(&key), "duplicate key {:?}", key);
+                rows.insert(key, fields.collect());
+            }
+            Operation::Update => rows
+                .get_mut(&key)
+                .unwrap_or_else(|| panic!("no row {:?}", key))
+                .extend(fields),
+            Operation::Upsert => rows.entry(key).or_default().extend(fields),
+            operation => panic!("unexpected {:?}", operation),
+        }
+    }
+}
+
+#[test]
+fn mints_a_burned_token_again() {
+    let holder = [0x01; 20];
+    let minter = [0x02; 20];
+    let mut rows = Rows::new();
+    let blocks = [
+        transfer([0; 20], holder),
+        transfer(holder, [0; 20]),
+        transfer([0; 20], minter),
+    ];
+    for (offset, log) in blocks.into_iter().enumerate() {
+        let mut blk = block(vec![transaction(0, vec![log])]);
+        blk.number += offset as u64;
+        apply_tokens(&mut rows, db_out(&blk));
+    }
+
+    assert_eq!(rows.len(), 1);
+    let token = rows.values().next().unwrap();
+    assert_eq!(token["owner"], Hex(minter).to_string());
+    assert_eq!(token["burned"], "false");
+    assert_eq!(token["minted_block_number"], (BLOCK_NUMBER + 2).to_string());
+}
+
+#[test]
+fn mints_a_burned_token_again_within_a_block() {
+    let holder = [0x01; 20];
+    let minter = [0x02; 20];
+    let mut rows = Rows::new();
+    apply_tokens(
+        &mut rows,
+        db_out(&block(vec![
+            transaction(0, vec![transfer([0; 20], holder)]),
+            transaction(1, vec![transfer(holder, [0; 20])]),
+            transaction(2, vec![transfer([0; 20], minter)]),
+        ])),
+    );
+
+    let token = rows.values().next().unwrap();
+    assert_eq!(token["owner"], Hex(minter).to_string());
+    assert_eq!(token["burned"], "false");
+}
@@ -0,0 +1,153 @@
+//! Golden-file tests: `map_events` output for fixed blocks, compared with the files under
+//! `tests/golden`. Run with `UPDATE_GOLDEN=1` to rewrite them after an intended change, and
+//! review the diff.
+
+mod common;
+
+use common::*;
+use miracam::params::TrackedContracts;
+use miracam::pb::contract::v1 as contract;
+use prost::Message;
+use std::fs;
+use std::path::{Path, PathBuf};
+use substreams::Hex;
+use substreams_ethereum::pb::eth::v2 as eth;
+
+const FIXTURE_SUFFIX: &str = ".block.pb";
+
+#[test]
+fn synthetic_block_matches_golden_file() {
+    let blk = block(vec![
+        transaction(0, nft_logs()),
+        transaction(1, attester_logs()),
+        transaction(2, router_logs(7)),
+    ]);
+    // Fixtures go through their protobuf encoding, so the synthetic block does too.
+    let blk = eth::Block::decode(blk.encode_to_vec().as_slice()).unwrap();
+
+    let params = [NFT, ATTESTER, ROUTER]
+        .map(|address| format!("evt_addr:0x{}", Hex(address)))
+        .join(" || ");
+    let contracts = TrackedContracts::parse(&params, "evt_addr").unwrap();
+
+    assert_golden("synthetic", &miracam::map_tracked_events(&blk, &contracts));
+}
+
+#[test]
+fn fixture_blocks_match_golden_files() {
+    let contracts = TrackedContracts::parse(&manifest_param("map_events"), "evt_addr").unwrap();
+    let fixtures = fixtures();
+    assert!(
+        !fixtures.is_empty(),
+        "tests/fixtures has no .block.pb fixtures, see its README"
+    );
+
+    for fixture in fixtures {
+        let name = fixture
+            .file_name()
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .trim_end_matches(FIXTURE_SUFFIX)
+            .to_string();
+        let blk =
+            eth::Block::decode(fs::read(&fixture).unwrap().as_slice()).unwrap_or_else(|err| {
+                panic!(
+                    "{}: not an sf.ethereum.type.v2.Block: {}",
+                    fixture.display(),
+                    err
+                )
+            });
+
+        assert_golden(&name, &miracam::map_tracked_events(&blk, &contracts));
+    }
+}
+
+fn tests_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests")
+}
+
+// Every `tests/fixtures/<name>.block.pb`, in name order.
+fn fixtures() -> Vec<PathBuf> {
+    let mut fixtures = fs::read_dir(tests_dir().join("fixtures"))
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| {
+            path.to_str()
+                .is_some_and(|path| path.ends_with(FIXTURE_SUFFIX))
+        })
+        .collect::<Vec<_>>();
+    fixtures.sort();
+    fixtures
+}
+
+// The value of `param` in the `params:` section of substreams.yaml, so block fixtures are
+// mapped with the contracts the package tracks.
+fn manifest_param(param: &str) -> String {
+    let manifest =
+        fs::read_to_string(Path::new(env!("CARGO_MANIFEST_DIR")).join("substreams.yaml")).unwrap();
+    let prefix = format!("{}:", param);
+    manifest
+        .lines()
+        .skip_while(|line| *line != "params:")
+        .find_map(|line| line.trim().strip_prefix(&prefix))
+        .map(|value| value.trim().trim_matches('"').to_string())
+        .unwrap_or_else(|| panic!("substreams.yaml has no {} param", param))
+}
+
+fn assert_golden(name: &str, events: &contract::Events) {
+    let path = tests_dir().join("golden").join(format!("{}.txt", name));
+    let actual = render(events);
+
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        fs::write(&path, actual).unwrap();
+        return;
+    }
+
+    let expected = fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "{} is missing, run with UPDATE_GOLDEN=1 to create it",
+            path.display()
+        )
+    });
+    assert!(
+        expected == actual,
+        "{} does not match, run with UPDATE_GOLDEN=1 and review the diff",
+        path.display()
+    );
+}
+
+// `{:#?}` puts every byte of a `Vec<u8>` on a line of its own; keep them on the line of their
+// field.
+fn render(events: &contract::Events) -> String {
+    let mut rendered = String::new();
+    let mut in_bytes = false;
+    for line in format!("{:#?}", events).lines() {
+        let trimmed = line.trim();
+        let is_byte = trimmed
+            .strip_suffix(',')
+            .is_some_and(|byte| byte.parse::<u8>().is_ok());
+
+        if is_byte && (in_bytes || rendered.ends_with('[')) {
+            if in_bytes {
+                rendered.push(' ');
+            }
+            rendered.push_str(trimmed);
+            in_bytes = true;
+        } else if in_bytes && trimmed.starts_with(']') {
+            rendered.pop();
+            rendered.push_str(trimmed);
+            rendered.push('\n');
+            in_bytes = false;
+        } else {
+            if rendered.ends_with('[') {
+                rendered.push('\n');
+            }
+            rendered.push_str(line);
+            if !line.ends_with('[') {
+                rendered.push('\n');
+            }
+        }
+    }
+    rendered
+}
@@ -0,0 +1,308 @@
+mod common;
+
+use common::*;
+use miracam::abi::{attester_contract, nft_contract, router_contract};
+use miracam::params::TrackedContracts;
+use miracam::pb::contract::v1 as contract;
+use substreams::scalar::BigInt;
+use substreams::Hex;
+use substreams_ethereum::pb::eth::v2 as eth;
+use substreams_ethereum::Event;
+
+fn tracked(addresses: &[[u8; 20]]) -> TrackedContracts {
+    let params = addresses
+        .iter()
+        .map(|address| format!("evt_addr:0x{}", Hex(address)))
+        .collect::<Vec<_>>();
+    TrackedContracts::parse(&params.join(" || "), "evt_addr").unwrap()
+}
+
+fn all_logs() -> Vec<eth::Log> {
+    nft_logs()
+        .into_iter()
+        .chain(attester_logs())
+        .chain(router_logs(7))
+        .collect()
+}
+
+#[test]
+fn encoded_logs_decode_back_to_their_event() {
+    let transfer = nft_contract::events::Transfer {
+        from: vec![0; 20],
+        to: vec![0x01; 20],
+        token_id: BigInt::from(42),
+    };
+    assert_eq!(
+        nft_contract::events::Transfer::match_and_decode(transfer.to_log(&NFT)),
+        Some(transfer)
+    );
+
+    let attested = attester_contract::events::Attested {
+        request_id: request_id(0x0a),
+        owner: vec![0x01; 20],
+        attester: "miracam-camera-01".to_string(),
+        url: "ipfs://bafyphoto".to_string(),
+    };
+    assert_eq!(
+        attester_contract::events::Attested::match_and_decode(attested.to_log(&ATTESTER)),
+        Some(attested)
+    );
+
+    let processed = router_contract::events::RequestProcessed {
+        request_id: request_id(0x0a),
+        subscription_id: BigInt::from(7),
+        total_cost_juels: BigInt::from(150),
+        transmitter: vec![0x07; 20],
+        result_code: BigInt::from(1),
+        response: vec![0xbe, 0xef],
+        err: b"timeout".to_vec(),
+        callback_return_data: vec![],
+    };
+    assert_eq!(
+        router_contract::events::RequestProcessed::match_and_decode(processed.to_log(&ROUTER)),
+        Some(processed)
+    );
+}
+
+#[test]
+fn decodes_every_event_type() {
+    let blk = block(vec![transaction(0, all_logs())]);
+    let events = miracam::map_tracked_events(&blk, &tracked(&[NFT, ATTESTER, ROUTER]));
+
+    let lengths = [
+        events.nft_approvals.len(),
+        events.nft_approval_for_alls.len(),
+        events.nft_batch_metadata_updates.len(),
+        events.nft_metadata_updates.len(),
+        events.nft_role_admin_changeds.len(),
+        events.nft_role_granteds.len(),
+        events.nft_role_revokeds.len(),
+        events.nft_transfers.len(),
+        events.attester_attesteds.len(),
+        events.attester_ownership_transfer_requesteds.len(),
+        events.attester_ownership_transferreds.len(),
+        events.attester_request_fulfilleds.len(),
+        events.attester_request_sents.len(),
+        events.attester_responses.len(),
+        events.router_request_not_processeds.len(),
+        events.router_request_processeds.len(),
+        events.router_request_starts.len(),
+        events.router_request_timed_outs.len(),
+        events.router_subscription_canceleds.len(),
+        events.router_subscription_consumer_addeds.len(),
+        events.router_subscription_consumer_removeds.len(),
+        events.router_subscription_createds.len(),
+        events.router_subscription_fundeds.len(),
+        events.router_subscription_owner_transfer_requesteds.len(),
+        events.router_subscription_owner_transferreds.len(),
+    ];
+    assert_eq!(lengths, [1; 25]);
+
+    let transfer = &events.nft_transfers[0];
+    assert_eq!(transfer.from, vec![0; 20]);
+    assert_eq!(transfer.to, vec![0x01; 20]);
+    assert_eq!(transfer.token_id, "42");
+    assert_eq!(transfer.contract_address, NFT.to_vec());
+    assert_eq!(transfer.evt_tx_hash, Hex(vec![1u8; 32]).to_string());
+    assert_eq!(transfer.evt_index, 0);
+    assert_eq!(transfer.evt_block_number, BLOCK_NUMBER);
+    assert_eq!(transfer.evt_block_time.unwrap().seconds, BLOCK_TIMESTAMP);
+
+    let approval_for_all = &events.nft_approval_for_alls[0];
+    assert_eq!(
+        (approval_for_all.operator.clone(), approval_for_all.approved),
+        (vec![0x03; 20], true)
+    );
+
+    let batch = &events.nft_batch_metadata_updates[0];
+    assert_eq!(
+        (batch.u_from_token_id.as_str(), batch.u_to_token_id.as_str()),
+        ("1", "3")
+    );
+
+    let role_admin_changed = &events.nft_role_admin_changeds[0];
+    assert_eq!(role_admin_changed.role, vec![0x9f; 32]);
+    assert_eq!(role_admin_changed.new_admin_role, vec![0x01; 32]);
+
+    let attested = &events.attester_attesteds[0];
+    assert_eq!(attested.request_id, request_id(0x0a).to_vec());
+    assert_eq!(attested.owner, vec![0x01; 20]);
+    assert_eq!(attested.attester, "miracam-camera-01");
+    assert_eq!(attested.url, "ipfs://bafyphoto");
+    assert_eq!(attested.contract_address, ATTESTER.to_vec());
+
+    let start = &events.router_request_starts[0];
+    assert_eq!(start.subscription_id, 7);
+    assert_eq!(start.callback_gas_limit, 300_000);
+    assert_eq!(start.estimated_total_cost_juels, "200000000000000000");
+
+    let funded = &events.router_subscription_fundeds[0];
+    assert_eq!(funded.new_balance, "5000000000000000000");
+}
+
+#[test]
+fn events_keep_log_order_across_types() {
+    let blk = block(vec![
+        transaction(0, nft_logs()),
+        transaction(1, attester_logs()),
+    ]);
+    let events = miracam::map_tracked_events(&blk, &tracked(&[NFT, ATTESTER]));
+
+    assert_eq!(events.nft_transfers[0].evt_index, 0);
+    assert_eq!(events.nft_role_revokeds[0].evt_index, 7);
+    assert_eq!(
+        events.attester_ownership_transfer_requesteds[0].evt_index,
+        8
+    );
+    assert_eq!(events.attester_attesteds[0].evt_index, 13);
+    assert_eq!(
+        events.attester_attesteds[0].evt_tx_hash,
+        Hex(vec![2u8; 32]).to_string()
+    );
+}
+
+#[test]
+fn skips_untracked_contracts() {
+    let mut logs = all_logs();
+    for log in logs.iter_mut() {
+        log.address = UNTRACKED.to_vec();
+    }
+    let blk = block(vec![transaction(0, logs)]);
+
+    let events = miracam::map_tracked_events(&blk, &tracked(&[NFT, ATTESTER, ROUTER]));
+    assert_eq!(events, contract::Events::default());
+}
+
+#[test]
+fn skips_failed_transactions() {
+    let mut failed = transaction(0, all_logs());
+    failed.status = eth::TransactionTraceStatus::Reverted as i32;
+    let blk = block(vec![failed, transaction(1, nft_logs())]);
+
+    let events = miracam::map_tracked_events(&blk, &tracked(&[NFT, ATTESTER, ROUTER]));
+    assert_eq!(events.nft_transfers.len(), 1);
+    assert_eq!(
+        events.nft_transfers[0].evt_tx_hash,
+        Hex(vec![2u8; 32]).to_string()
+    );
+    assert!(events.attester_attesteds.is_empty());
+}
+
+#[test]
+fn fills_event_context() {
+    let blk = block(vec![transaction(3, nft_logs())]);
+    let events = miracam::map_tracked_events(&blk, &tracked(&[NFT]));
+
+    let context = events.nft_approvals[0].evt_context.clone().unwrap();
+    assert_eq!(
+        context,
+        contract::EventContext {
+            block_hash: Hex(vec![0xcc; 32]).to_string(),
+            tx_index: 3,
+            tx_from: vec![0xaa; 20],
+            tx_to: vec![0xbb; 20],
+            tx_nonce: 7,
+            tx_gas_used: 21_000,
+            tx_effective_gas_price: "2000000000".to_string(),
+            log_ordinal: 101,
+        }
+    );
+}
+
+#[test]
+fn emits_bigint_companions() {
+    let large = BigInt::from(u64::MAX) * BigInt::from(16);
+    let logs = vec![
+        nft_contract::events::Transfer {
+            from: vec![0; 20],
+            to: vec![0x01; 20],
+            token_id: BigInt::from(42),
+        }
+        .to_log(&NFT),
+        nft_contract::events::Transfer {
+            from: vec![0; 20],
+            to: vec![0x01; 20],
+            token_id: large.clone(),
+        }
+        .to_log(&NFT),
+    ];
+    let blk = block(vec![transaction(0, logs)]);
+    let events = miracam::map_tracked_events(&blk, &tracked(&[NFT]));
+
+    let small = events.nft_transfers[0].token_id_bigint.clone().unwrap();
+    assert_eq!((small.be_bytes, small.u64_value), (vec![42], Some(42)));
+
+    let wide = events.nft_transfers[1].token_id_bigint.clone().unwrap();
+    assert_eq!(wide.u64_value, None);
+    assert_eq!(BigInt::from(&wide), large);
+    assert_eq!(events.nft_transfers[1].token_id, large.to_string());
+}
+
+#[test]
+fn skips_events_with_integers_wider_than_their_abi_type() {
+    let not_processed = |result_code: BigInt| {
+        router_contract::events::RequestNotProcessed {
+            request_id: request_id(0x0b),
+            coordinator: vec![0x08; 20],
+            transmitter: vec![0x07; 20],
+            result_code,
+        }
+        .to_log(&ROUTER)
+    };
+    let logs = vec![
+        not_processed(BigInt::from(u64::MAX) * BigInt::from(16)),
+        not_processed(BigInt::from(4)),
+    ];
+    let blk = block(vec![transaction(0, logs)]);
+    let events = miracam::map_tracked_events(&blk, &tracked(&[ROUTER]));
+
+    assert_eq!(events.router_request_not_processeds.len(), 1);
+    assert_eq!(events.router_request_not_processeds[0].result_code, 4);
+}
+
+#[test]
+fn decodes_attester_responses() {
+    let logs = vec![
+        attester_contract::events::Response {
+            request_id: request_id(0x0a),
+            response: ethabi::encode(&[ethabi::Token::String("verified".to_string())]),
+            err: vec![],
+        }
+        .to_log(&ATTESTER),
+        attester_contract::events::Response {
+            request_id: request_id(0x0b),
+            response: vec![],
+            err: b"photo signature mismatch".to_vec(),
+        }
+        .to_log(&ATTESTER),
+    ];
+    let blk = block(vec![transaction(0, logs)]);
+    let events = miracam::map_tracked_events(&blk, &tracked(&[ATTESTER]));
+
+    let verified = &events.attester_responses[0];
+    assert_eq!(
+        (verified.response_text.as_str(), verified.is_error),
+        ("verified", false)
+    );
+
+    let errored = &events.attester_responses[1];
+    assert_eq!(
+        (errored.error_text.as_str(), errored.is_error),
+        ("photo signature mismatch", true)
+    );
+}
+
+#[test]
+fn rejects_checksummed_addresses() {
+    // The block index keys are lowercase, so this filter would match no block.
+    let err = TrackedContracts::parse(
+        "evt_addr:0x4B79800e11fa527b01685056970d62878240ea46",
+        "evt_addr",
+    )
+    .err()
+    .unwrap();
+    assert_eq!(
+        err.to_string(),
+        "invalid address \"0x4B79800e11fa527b01685056970d62878240ea46\" in params: expected lowercase hex, as in the block index keys"
+    );
+}
@@ -0,0 +1,442 @@
+#![cfg(feature = "replay")]
+
+mod common;
+
+use common::*;
+use miracam::abi::{attester_contract, nft_contract, router_contract};
+use miracam::replay::{Replay, MODULES};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use substreams::scalar::BigInt;
+use substreams::Hex;
+use substreams_ethereum::pb::eth::v2 as eth;
+
+fn params() -> HashMap<String, String> {
+    let contracts = format!("evt_addr:0x{} || evt_addr:0x{}", Hex(NFT), Hex(ATTESTER));
+    [
+        ("map_events", contracts.clone()),
+        ("map_calls", contracts.replace("evt_addr", "call_to")),
+        ("map_failures", contracts.replace("evt_addr", "call_to")),
+        ("map_router_events", format!("router:0x{}", Hex(ROUTER))),
+        ("map_provenance", "7200".to_string()),
+        ("map_metadata_refreshes", "1000".to_string()),
+        ("map_stale_transfers", "7200".to_string()),
+    ]
+    .into_iter()
+    .map(|(module, params)| (module.to_string(), params))
+    .collect()
+}
+
+fn output<'a>(lines: &'a [Value], module: &str) -> Option<&'a Value> {
+    lines.iter().find(|line| line["@module"] == module)
+}
+
+#[test]
+fn requires_module_params() {
+    let mut params = params();
+    params.remove("map_provenance");
+
+    let err = Replay::new(&params).err().unwrap();
+    assert_eq!(err.to_string(), "missing params for map_provenance");
+}
+
+#[test]
+fn runs_every_module_of_the_manifest() {
+    let manifest: serde_yaml::Value =
+        serde_yaml::from_str(include_str!("../substreams.yaml")).unwrap();
+    let mut modules = manifest["modules"]
+        .as_sequence()
+        .unwrap()
+        .iter()
+        .map(|module| module["name"].as_str().unwrap())
+        .filter(|name| !["index_events", "index_calls", "db_out", "graph_out"].contains(name))
+        .collect::<Vec<_>>();
+    let mut replayed = MODULES.to_vec();
+    modules.sort();
+    replayed.sort();
+    assert_eq!(replayed, modules);
+}
+
+#[test]
+fn prints_map_outputs_as_json_lines() {
+    let replay = Replay::new(&params()).unwrap();
+    let lines = replay
+        .process(&block(vec![transaction(0, nft_logs())]))
+        .unwrap();
+
+    let events = output(&lines, "map_events").unwrap();
+    assert_eq!(events["@block"], BLOCK_NUMBER);
+    assert_eq!(events["@type"], "contract.v1.Events");
+    let transfer = &events["@data"]["nft_transfers"][0];
+    assert_eq!(transfer["to"], Hex([0x01; 20]).to_string());
+    assert_eq!(transfer["token_id"], "42");
+    assert_eq!(transfer["evt_block_time"], "2023-11-14T22:13:20Z");
+
+    // Nothing touched an attester contract, so there are no requests to report.
+    assert!(output(&lines, "map_oracle_requests").is_none());
+}
+
+#[test]
+fn keeps_stores_across_blocks() {
+    let replay = Replay::new(&params()).unwrap();
+    let holder = [0x01; 20];
+    let buyer = [0x02; 20];
+    let transfer = |from: [u8; 20], to: [u8; 20]| {
+        nft_contract::events::Transfer {
+            from: from.to_vec(),
+            to: to.to_vec(),
+            token_id: BigInt::from(42),
+        }
+        .to_log(&NFT)
+    };
+
+    replay
+        .process(&block(vec![transaction(
+            0,
+            vec![transfer([0; 20], holder)],
+        )]))
+        .unwrap();
+    let mut next = block(vec![transaction(0, vec![transfer(holder, buyer)])]);
+    next.number += 1;
+    let lines = replay.process(&next).unwrap();
+
+    let owner_key = format!("token:{}:{:064x}:owner", Hex(NFT), 42);
+    assert_eq!(
+        output(&lines, "store_token_owners").unwrap()["@data"]["deltas"],
+        json!([{
+            "operation": "UPDATE",
+            "ordinal": 0,
+            "key": owner_key,
+            "old_value": Hex(holder).to_string(),
+            "new_value": Hex(buyer).to_string(),
+        }])
+    );
+
+    let changes = &output(&lines, "map_balance_changes").unwrap()["@data"]["balance_changes"];
+    assert_eq!(changes[0]["owner"], Hex(holder).to_string());
+    assert_eq!(
+        (
+            &changes[0]["old_balance"],
+            &changes[0]["new_balance"],
+            &changes[0]["emptied"]
+        ),
+        (&json!("1"), &json!("0"), &json!(true))
+    );
+    assert_eq!(changes[1]["owner"], Hex(buyer).to_string());
+    assert_eq!(changes[1]["new_balance"], "1");
+}
+
+#[test]
+fn writes_enums_by_name() {
+    let replay = Replay::new(&params()).unwrap();
+    let lines = replay
+        .process(&block(vec![transaction(0, attester_logs())]))
+        .unwrap();
+
+    let requests = &output(&lines, "map_oracle_requests").unwrap()["@data"]["requests"];
+    assert_eq!(requests[0]["status"], "FULFILLED");
+}
+
+#[test]
+fn writes_every_bytes_field_as_hex() {
+    // A `bytes` field missing its `serialize_with` attribute in buf.gen.yaml would be written as
+    // an array of numbers; no field of the package is a repeated number.
+    fn assert_no_byte_arrays(value: &Value, path: &str) {
+        match value {
+            Value::Array(items) => {
+                assert!(
+                    items.is_empty() || !items.iter().all(Value::is_number),
+                    "{} is written as an array of numbers",
+                    path
+                );
+                for (i, item) in items.iter().enumerate() {
+                    assert_no_byte_arrays(item, &format!("{}[{}]", path, i));
+                }
+            }
+            Value::Object(fields) => {
+                for (name, field) in fields {
+                    assert_no_byte_arrays(field, &format!("{}.{}", path, name));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut params = params();
+    params.insert(
+        "map_router_events".to_string(),
+        format!("router:0x{} && subscription:7", Hex(ROUTER)),
+    );
+    let replay = Replay::new(&params).unwrap();
+    let lines = replay
+        .process(&block(vec![
+            transaction(0, nft_logs()),
+            transaction(1, attester_logs()),
+            transaction(2, router_logs(7)),
+        ]))
+        .unwrap();
+
+    assert!(lines.len() > 10);
+    for line in &lines {
+        assert_no_byte_arrays(&line["@data"], line["@module"].as_str().unwrap());
+    }
+}
+
+#[test]
+fn alerts_stale_transfers_on_their_deadline_block_without_events() {
+    let replay = Replay::new(&params()).unwrap();
+    let at = |number: u64, logs: Vec<eth::Log>| {
+        let mut blk = block(vec![transaction(0, logs)]);
+        blk.number = number;
+        blk
+    };
+    let requested = attester_contract::events::OwnershipTransferRequested {
+        from: vec![0x01; 20],
+        to: vec![0x06; 20],
+    };
+    replay
+        .process(&at(BLOCK_NUMBER, vec![requested.to_log(&ATTESTER)]))
+        .unwrap();
+
+    let lines = replay.process(&at(BLOCK_NUMBER + 7199, vec![])).unwrap();
+    assert!(output(&lines, "map_stale_transfers").is_none());
+
+    let lines = replay.process(&at(BLOCK_NUMBER + 7200, vec![])).unwrap();
+    let alerts = &output(&lines, "map_stale_transfers").unwrap()["@data"]["alerts"];
+    assert_eq!(alerts.as_array().unwrap().len(), 1);
+    assert_eq!(alerts[0]["blocks_pending"], 7200);
+    assert_eq!(
+        alerts[0]["ownership"]["pending_owner"],
+        Hex([0x06; 20]).to_string()
+    );
+
+    let lines = replay.process(&at(BLOCK_NUMBER + 7201, vec![])).unwrap();
+    assert!(output(&lines, "map_stale_transfers").is_none());
+}
+
+#[test]
+fn rejects_invalid_alert_params() {
+    let mut params = params();
+    params.insert("map_stale_transfers".to_string(), "0".to_string());
+
+    let replay = Replay::new(&params).unwrap();
+    let err = replay.process(&block(vec![])).err().unwrap();
+    assert_eq!(
+        err.to_string(),
+        "invalid params \"0\": expected a number of blocks above zero"
+    );
+}
+
+#[test]
+fn splits_a_transaction_between_its_requests() {
+    let replay = Replay::new(&params()).unwrap();
+    let response = |id: u8| {
+        attester_contract::events::Response {
+            request_id: request_id(id),
+            response: vec![],
+            err: vec![],
+        }
+        .to_log(&ATTESTER)
+    };
+    let lines = replay
+        .process(&block(vec![transaction(
+            0,
+            vec![response(0x0a), response(0x0b)],
+        )]))
+        .unwrap();
+
+    // 21,000 gas at 2 gwei, shared by the two responses.
+    let costs = &output(&lines, "map_attestation_costs").unwrap()["@data"]["costs"];
+    for cost in costs.as_array().unwrap() {
+        assert_eq!(cost["fulfilled"]["cost_wei"], "21000000000000");
+        assert_eq!(cost["fulfilled"]["requests_in_tx"], 2);
+    }
+    assert_eq!(costs.as_array().unwrap().len(), 2);
+
+    let deltas = &output(&lines, "store_daily_costs").unwrap()["@data"]["deltas"];
+    let fulfillment = deltas
+        .as_array()
+        .unwrap()
+        .iter()
+        .rfind(|delta| {
+            delta["key"]
+                .as_str()
+                .unwrap()
+                .ends_with(":fulfillment_cost_wei")
+        })
+        .unwrap();
+    assert_eq!(fulfillment["new_value"], "42000000000000");
+}
+
+#[test]
+fn keeps_router_events_of_the_subscription_and_its_requests() {
+    let mut params = params();
+    params.insert(
+        "map_router_events".to_string(),
+        format!("router:0x{} && subscription:7", Hex(ROUTER)),
+    );
+    let replay = Replay::new(&params).unwrap();
+
+    let sent = attester_contract::events::RequestSent {
+        id: request_id(0x0c),
+    }
+    .to_log(&ATTESTER);
+    let router = vec![
+        router_contract::events::RequestTimedOut {
+            request_id: request_id(0x0c),
+        }
+        .to_log(&ROUTER),
+        // Sent by another consumer of the router.
+        router_contract::events::RequestTimedOut {
+            request_id: request_id(0x0d),
+        }
+        .to_log(&ROUTER),
+        // The router's own ownership transfer, with the signature of the attester one.
+        attester_contract::events::OwnershipTransferRequested {
+            from: vec![0x01; 20],
+            to: vec![0x06; 20],
+        }
+        .to_log(&ROUTER),
+    ];
+    let lines = replay
+        .process(&block(vec![
+            transaction(0, vec![sent]),
+            transaction(1, router),
+        ]))
+        .unwrap();
+
+    let events = &output(&lines, "map_router_events").unwrap()["@data"];
+    let timed_out = events["router_request_timed_outs"].as_array().unwrap();
+    assert_eq!(timed_out.len(), 1);
+    assert_eq!(
+        timed_out[0]["request_id"],
+        Hex(request_id(0x0c)).to_string()
+    );
+    assert_eq!(events["attester_ownership_transfer_requesteds"], json!([]));
+}
+
+#[test]
+fn keeps_router_events_of_the_sent_requests_without_a_subscription() {
+    let replay = Replay::new(&params()).unwrap();
+
+    let sent = attester_contract::events::RequestSent {
+        id: request_id(0x0a),
+    }
+    .to_log(&ATTESTER);
+    let mut router = router_logs(7);
+    // Another consumer's request, on another subscription.
+    router.push(
+        router_contract::events::RequestStart {
+            request_id: request_id(0x0e),
+            don_id: [0x0d; 32],
+            subscription_id: BigInt::from(8),
+            subscription_owner: vec![0x09; 20],
+            requesting_contract: vec![0x09; 20],
+            request_initiator: vec![0x09; 20],
+            data: vec![],
+            data_version: BigInt::from(1),
+            callback_gas_limit: BigInt::from(300_000),
+            estimated_total_cost_juels: BigInt::from(1),
+        }
+        .to_log(&ROUTER),
+    );
+    let lines = replay
+        .process(&block(vec![
+            transaction(0, vec![sent]),
+            transaction(1, router),
+        ]))
+        .unwrap();
+
+    let events = &output(&lines, "map_router_events").unwrap()["@data"];
+    let request_ids = |list: &str| {
+        events[list]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|evt| evt["request_id"].clone())
+            .collect::<Vec<_>>()
+    };
+    let sent_id = json!(Hex(request_id(0x0a)).to_string());
+    assert_eq!(request_ids("router_request_starts"), [sent_id]);
+    assert_eq!(
+        request_ids("router_request_processeds"),
+        request_ids("router_request_starts")
+    );
+    assert_eq!(events["router_subscription_fundeds"], json!([]));
+    assert_eq!(events["router_subscription_createds"], json!([]));
+}
+
+#[test]
+fn links_no_photo_without_a_url() {
+    let replay = Replay::new(&params()).unwrap();
+    let owner = [0x01; 20];
+    let attested = attester_contract::events::Attested {
+        request_id: request_id(0x0a),
+        owner: owner.to_vec(),
+        attester: "attester".to_string(),
+        url: String::new(),
+    };
+    replay
+        .process(&block(vec![transaction(
+            0,
+            vec![attested.to_log(&ATTESTER)],
+        )]))
+        .unwrap();
+
+    // Minted without a `safeMint` call, so with no token uri.
+    let mint = nft_contract::events::Transfer {
+        from: vec![0; 20],
+        to: owner.to_vec(),
+        token_id: BigInt::from(42),
+    };
+    let mut next = block(vec![transaction(0, vec![mint.to_log(&NFT)])]);
+    next.number += 1;
+    let lines = replay.process(&next).unwrap();
+
+    assert!(output(&lines, "map_mints").is_some());
+    assert!(output(&lines, "map_provenance").is_none());
+}
+
+#[test]
+fn pairs_mints_with_the_safe_mint_of_their_deployment() {
+    let other_nft = [0x55; 20];
+    let mut params = params();
+    let contracts = format!(
+        "evt_addr:0x{} || evt_addr:0x{} || evt_addr:0x{}",
+        Hex(NFT),
+        Hex(other_nft),
+        Hex(ATTESTER)
+    );
+    params.insert(
+        "map_calls".to_string(),
+        contracts.replace("evt_addr", "call_to"),
+    );
+    params.insert("map_events".to_string(), contracts);
+    let replay = Replay::new(&params).unwrap();
+
+    let recipient = [0x01; 20];
+    let mint = |token_id: u64| nft_contract::events::Transfer {
+        from: vec![0; 20],
+        to: recipient.to_vec(),
+        token_id: BigInt::from(token_id),
+    };
+    let safe_mint = nft_contract::functions::SafeMint {
+        to: recipient.to_vec(),
+        uri: "ipfs://other".to_string(),
+    };
+    let mut tx = transaction(0, vec![mint(42).to_log(&NFT), mint(7).to_log(&other_nft)]);
+    tx.calls = vec![call(&other_nft, safe_mint.encode())];
+    let lines = replay.process(&block(vec![tx])).unwrap();
+
+    let mints = &output(&lines, "map_mints").unwrap()["@data"]["mints"];
+    assert_eq!(mints[0]["contract_address"], Hex(NFT).to_string());
+    assert_eq!(mints[0]["uri"], "");
+    assert_eq!(mints[1]["contract_address"], Hex(other_nft).to_string());
+    assert_eq!(mints[1]["uri"], "ipfs://other");
+
+    let safe_mints = &output(&lines, "map_calls").unwrap()["@data"]["nft_call_safe_mints"];
+    assert_eq!(
+        safe_mints[0]["contract_address"],
+        Hex(other_nft).to_string()
+    );
+}